@@ -0,0 +1,102 @@
+//! Matching workers to tasks by distance.
+//!
+//! [`assign`] pairs up creeps and tasks so that short trips are taken first.
+//! It only depends on the positions and order of its inputs, so calling it
+//! with the same creeps and tasks every tick keeps producing the same pairs
+//! instead of flip-flopping between equally good choices.
+use crate::{local::Position, objects::HasPosition};
+
+/// Greedily assigns each creep to at most one task, and each task to at most
+/// one creep, preferring the pairs with the smallest range between them.
+///
+/// Returns `(creep_index, task_index)` pairs, ordered by range. When there are
+/// more creeps than tasks (or the other way round) the leftovers are simply
+/// not assigned.
+///
+/// Ties in range are broken by creep index and then task index, so the result
+/// is stable as long as the inputs keep the same order.
+///
+/// Runs in `O(n * m * log(n * m))` time for `n` creeps and `m` tasks.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::{assignment::assign, find, game, prelude::*};
+///
+/// let room = game::rooms::values().pop().unwrap();
+/// let creeps = room.find(find::MY_CREEPS);
+/// let sites = room.find(find::CONSTRUCTION_SITES);
+///
+/// for (creep, site) in assign(&creeps, &sites) {
+///     creeps[creep].build(&sites[site]);
+/// }
+/// ```
+pub fn assign<C, T>(creeps: &[C], tasks: &[T]) -> Vec<(usize, usize)>
+where
+    C: HasPosition,
+    T: HasPosition,
+{
+    let creep_positions: Vec<Position> = creeps.iter().map(HasPosition::pos).collect();
+    let task_positions: Vec<Position> = tasks.iter().map(HasPosition::pos).collect();
+    assign_positions(&creep_positions, &task_positions)
+}
+
+fn assign_positions(creeps: &[Position], tasks: &[Position]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::with_capacity(creeps.len() * tasks.len());
+    for (c, creep) in creeps.iter().enumerate() {
+        for (t, task) in tasks.iter().enumerate() {
+            pairs.push((creep.get_range_to(task), c, t));
+        }
+    }
+    pairs.sort_unstable();
+
+    let mut creep_taken = vec![false; creeps.len()];
+    let mut task_taken = vec![false; tasks.len()];
+    let mut result = Vec::with_capacity(creeps.len().min(tasks.len()));
+    for (_, c, t) in pairs {
+        if creep_taken[c] || task_taken[t] {
+            continue;
+        }
+        creep_taken[c] = true;
+        task_taken[t] = true;
+        result.push((c, t));
+        if result.len() == result.capacity() {
+            break;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::assign_positions;
+    use crate::local::{Position, RoomName};
+
+    fn pos(x: u32, y: u32) -> Position {
+        Position::new(x, y, RoomName::new("E1N1").unwrap())
+    }
+
+    #[test]
+    fn nearest_pairs_first() {
+        let creeps = [pos(10, 10), pos(40, 40)];
+        let tasks = [pos(38, 40), pos(11, 10), pos(25, 25)];
+        assert_eq!(assign_positions(&creeps, &tasks), vec![(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn ties_are_stable() {
+        let creeps = [pos(10, 10), pos(12, 10)];
+        let tasks = [pos(11, 10), pos(11, 11)];
+        let first = assign_positions(&creeps, &tasks);
+        assert_eq!(first, vec![(0, 0), (1, 1)]);
+        assert_eq!(assign_positions(&creeps, &tasks), first);
+    }
+
+    #[test]
+    fn leftovers_unassigned() {
+        let creeps = [pos(10, 10), pos(20, 20), pos(30, 30)];
+        let tasks = [pos(29, 29)];
+        assert_eq!(assign_positions(&creeps, &tasks), vec![(2, 0)]);
+        assert!(assign_positions(&creeps, &[]).is_empty());
+    }
+}
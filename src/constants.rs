@@ -63,6 +63,7 @@ pub mod find;
 pub mod look;
 mod numbers;
 mod recipes;
+mod server;
 mod small_enums;
 mod types;
 
@@ -70,7 +71,7 @@ pub use self::{
     find::FindConstant,
     look::{Look, LookConstant},
     numbers::*,
-    recipes::FactoryRecipe,
+    recipes::{reaction_plan, FactoryRecipe, ReactionStep},
     small_enums::*,
     types::*,
 };
@@ -188,8 +189,8 @@ pub mod source {
 /// [market]: crate::game::market
 pub mod market {
     pub use super::numbers::{
-        MARKET_FEE, MARKET_MAX_ORDERS, MARKET_ORDER_LIFE_TIME, TERMINAL_CAPACITY,
-        TERMINAL_COOLDOWN, TERMINAL_MIN_SEND, TERMINAL_SEND_COST,
+        terminal_send_cost, MARKET_FEE, MARKET_MAX_ORDERS, MARKET_ORDER_LIFE_TIME,
+        TERMINAL_CAPACITY, TERMINAL_COOLDOWN, TERMINAL_MIN_SEND, TERMINAL_SEND_COST,
     };
 }
 
@@ -208,8 +209,8 @@ pub mod spawn {
 /// [`StructureTower`]: crate::objects::StructureTower
 pub mod tower {
     pub use super::numbers::{
-        TOWER_CAPACITY, TOWER_ENERGY_COST, TOWER_FALLOFF, TOWER_FALLOFF_RANGE, TOWER_OPTIMAL_RANGE,
-        TOWER_POWER_ATTACK, TOWER_POWER_HEAL, TOWER_POWER_REPAIR,
+        tower_effectiveness, TOWER_CAPACITY, TOWER_ENERGY_COST, TOWER_FALLOFF, TOWER_FALLOFF_RANGE,
+        TOWER_OPTIMAL_RANGE, TOWER_POWER_ATTACK, TOWER_POWER_HEAL, TOWER_POWER_REPAIR,
     };
 }
 
@@ -52,6 +52,8 @@ pub unsafe trait FindConstant {
 /// *Note*: To avoid ambiguity with [`RoomObject`], you should refer to this
 /// enum as `find::RoomObject` rather than importing it directly.
 ///
+/// Serializes and deserializes as its numeric `FIND_*` value.
+///
 /// [`RoomObject`]: crate::objects::RoomObject
 #[derive(
     Debug, PartialEq, Eq, Clone, Copy, Hash, FromPrimitive, Deserialize_repr, Serialize_repr,
@@ -170,3 +172,25 @@ typesafe_find_constants! {
     pub struct EXIT_LEFT = (Exit::Left as i16, Position);
     pub struct EXIT = (Exit::All as i16, Position);
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Exit, RoomObject};
+    use crate::constants::Look;
+
+    #[test]
+    fn find_and_look_constants_serialize_as_numbers() {
+        assert_eq!(
+            serde_json::to_string(&RoomObject::HostileCreeps).unwrap(),
+            "103"
+        );
+        assert_eq!(serde_json::to_string(&Exit::Left).unwrap(), "7");
+        assert_eq!(serde_json::to_string(&Look::Structures).unwrap(), "5");
+
+        let find: RoomObject = serde_json::from_str("111").unwrap();
+        assert_eq!(find, RoomObject::ConstructionSites);
+        let look: Look = serde_json::from_str("6").unwrap();
+        assert_eq!(look, Look::Flags);
+        assert!(serde_json::from_str::<RoomObject>("1").is_err());
+    }
+}
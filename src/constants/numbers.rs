@@ -189,11 +189,7 @@ pub const EXTENSION_HITS: u32 = 1000;
 /// each source structure at a given room control level.
 #[inline]
 pub fn extension_energy_capacity(rcl: u32) -> u32 {
-    match rcl {
-        r if r < 7 => 50,
-        7 => 100,
-        8 | _ => 200,
-    }
+    super::server::extension_energy_capacity(rcl)
 }
 
 /// Maximum hits for road structures, before swamp/tunnel multipliers
@@ -256,16 +252,7 @@ pub const CONSTRUCTION_COST_ROAD_WALL_RATIO: u32 = 150;
 /// Returns `Some` for levels 1-7, `None` for all others.
 #[inline]
 pub fn controller_levels(current_rcl: u32) -> Option<u32> {
-    match current_rcl {
-        1 => Some(200),
-        2 => Some(45_000),
-        3 => Some(135_000),
-        4 => Some(405_000),
-        5 => Some(1_215_000),
-        6 => Some(3_645_000),
-        7 => Some(10_935_000),
-        _ => None,
-    }
+    super::server::controller_levels(current_rcl)
 }
 
 // CONTROLLER_STRUCTURES defined in `types.rs`
@@ -412,6 +399,26 @@ pub const TOWER_FALLOFF_RANGE: u32 = 20;
 /// [source]: https://github.com/screeps/engine/blob/f02d16a44a00c35615ae227fc72a3c9a07a6a39a/src/processor/intents/towers/attack.js#L38
 pub const TOWER_FALLOFF: f32 = 0.75;
 
+/// Multiplier applied to a tower's attack, heal or repair power for a target
+/// at `range`, following the formula documented on [`TOWER_FALLOFF`].
+///
+/// This is `1.0` up to [`TOWER_OPTIMAL_RANGE`], falling linearly to
+/// `1.0 - TOWER_FALLOFF` at [`TOWER_FALLOFF_RANGE`] and beyond. The game
+/// rounds the resulting amount down.
+///
+/// ```
+/// use screeps::constants::{tower_effectiveness, TOWER_POWER_ATTACK};
+///
+/// let damage = (f64::from(TOWER_POWER_ATTACK) * tower_effectiveness(10)) as u32;
+/// assert_eq!(damage, 450);
+/// ```
+#[inline]
+pub fn tower_effectiveness(range: u32) -> f64 {
+    let range = range.clamp(TOWER_OPTIMAL_RANGE, TOWER_FALLOFF_RANGE);
+    1.0 - f64::from(TOWER_FALLOFF) * f64::from(range - TOWER_OPTIMAL_RANGE)
+        / f64::from(TOWER_FALLOFF_RANGE - TOWER_OPTIMAL_RANGE)
+}
+
 /// Initial hits for observer structures; consider using the
 /// [`StructureType::initial_hits`] function.
 pub const OBSERVER_HITS: u32 = 500;
@@ -592,6 +599,31 @@ pub const TERMINAL_CAPACITY: u32 = 300_000;
 ///
 /// [`market::calc_transaction_cost`]: [`crate::market::calc_transaction_cost`].
 pub const TERMINAL_SEND_COST: f32 = 0.1;
+
+/// Energy a terminal spends to send `amount` of any resource to a room
+/// `range` rooms away, as computed by the game for
+/// [`StructureTerminal::send`].
+///
+/// `range` is the linear distance between the rooms, measured across world
+/// edges, as returned by `Game.map.getRoomLinearDistance(a, b, true)`. This
+/// matches [`market::calc_transaction_cost`], rounded up as the game does when
+/// charging it, without calling into JavaScript. Despite its name,
+/// [`TERMINAL_SEND_COST`] isn't used.
+///
+/// ```
+/// use screeps::constants::terminal_send_cost;
+///
+/// // sending 1000 from W0N0 to W10N5
+/// assert_eq!(terminal_send_cost(1000, 10), 284);
+/// ```
+///
+/// [`StructureTerminal::send`]: crate::objects::StructureTerminal::send
+/// [`market::calc_transaction_cost`]: crate::game::market::calc_transaction_cost
+#[inline]
+pub fn terminal_send_cost(amount: u32, range: u32) -> u32 {
+    (f64::from(amount) * (1.0 - (-f64::from(range) / 30.0).exp())).ceil() as u32
+}
+
 /// Currently unused in game.
 pub const TERMINAL_MIN_SEND: u32 = 100;
 /// Cooldown after a terminal is used before it can be used again.
@@ -676,6 +708,11 @@ pub const FLAGS_LIMIT: u32 = 10_000;
 /// [`game::cpu::generate_pixel`]: crate::game::cpu::generate_pixel
 pub const PIXEL_CPU_COST: u32 = 10_000;
 
+/// Maximum amount of CPU which can be stored in [`game::cpu::bucket`].
+///
+/// [`game::cpu::bucket`]: crate::game::cpu::bucket
+pub const CPU_BUCKET_MAX: u32 = 10_000;
+
 // Resources defined in `types.rs`
 
 // REACTIONS defined in `recipes.rs`
@@ -831,3 +868,25 @@ pub const STRONGHOLD_DECAY_TICKS: u32 = 75_000;
 // BODYPARTS_ALL, RESOURCES_ALL, COLORS_ALL not yet implemented
 // INTERSHARD_RESOURCES defined in `types.rs`
 // COMMODITIES defined in `recipes.rs`
+
+#[cfg(test)]
+mod test {
+    use super::{terminal_send_cost, tower_effectiveness};
+
+    #[test]
+    fn tower_falloff() {
+        assert_eq!(tower_effectiveness(0), 1.0);
+        assert_eq!(tower_effectiveness(5), 1.0);
+        assert_eq!(tower_effectiveness(10), 0.75);
+        assert_eq!(tower_effectiveness(20), 0.25);
+        assert_eq!(tower_effectiveness(50), 0.25);
+    }
+
+    #[test]
+    fn terminal_cost() {
+        assert_eq!(terminal_send_cost(1000, 0), 0);
+        assert_eq!(terminal_send_cost(1000, 10), 284);
+        assert_eq!(terminal_send_cost(100, 1), 4);
+        assert!(terminal_send_cost(1000, 150) <= 1000);
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use crate::constants::ResourceType;
+use enum_iterator::IntoEnumIterator;
+
+use crate::constants::{ResourceType, LAB_REACTION_AMOUNT};
 
 #[derive(Clone, Debug)]
 pub struct FactoryRecipe {
@@ -15,7 +17,113 @@ pub struct FactoryRecipe {
     pub level: Option<u32>,
 }
 
+impl FactoryRecipe {
+    /// Whether a factory at `factory_level` (`None` for a factory with no
+    /// level) can produce this recipe.
+    ///
+    /// Level-restricted commodities can only be produced by a factory locked
+    /// to exactly that level.
+    pub fn allowed_at_level(&self, factory_level: Option<u32>) -> bool {
+        match self.level {
+            Some(level) => factory_level == Some(level),
+            None => true,
+        }
+    }
+}
+
+/// A single lab reaction in a plan produced by [`reaction_plan`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReactionStep {
+    /// Compound produced by this step
+    pub product: ResourceType,
+    /// The two input resources, each consumed in the same amount as produced
+    pub inputs: [ResourceType; 2],
+    /// Amount of the product made, always a multiple of
+    /// [`LAB_REACTION_AMOUNT`]
+    pub amount: u32,
+    /// Number of `runReaction` calls needed, without power effects
+    pub reactions: u32,
+}
+
+/// Plans the lab reactions needed to synthesize `amount` of `target`.
+///
+/// Walks the [`ResourceType::reaction_components`] tree, using resources in
+/// `available` before producing anything, and returns reactions in dependency
+/// order: every step's inputs are either available or produced by an earlier
+/// step. Each step's amount is rounded up to a multiple of
+/// [`LAB_REACTION_AMOUNT`], with any excess counted toward later steps.
+///
+/// Returns `None` if there aren't enough base resources in `available` to
+/// produce the target.
+pub fn reaction_plan(
+    target: ResourceType,
+    available: &HashMap<ResourceType, u32>,
+    amount: u32,
+) -> Option<Vec<ReactionStep>> {
+    fn plan(
+        resource: ResourceType,
+        amount: u32,
+        stock: &mut HashMap<ResourceType, u32>,
+        steps: &mut Vec<ReactionStep>,
+    ) -> Option<()> {
+        let in_stock = stock.entry(resource).or_insert(0);
+        let used = (*in_stock).min(amount);
+        *in_stock -= used;
+        let deficit = amount - used;
+        if deficit == 0 {
+            return Some(());
+        }
+
+        let inputs = resource.reaction_components()?;
+        let reactions = deficit.div_ceil(LAB_REACTION_AMOUNT);
+        let produced = reactions * LAB_REACTION_AMOUNT;
+        for &input in &inputs {
+            plan(input, produced, stock, steps)?;
+        }
+
+        *stock.entry(resource).or_insert(0) += produced - deficit;
+        steps.push(ReactionStep {
+            product: resource,
+            inputs,
+            amount: produced,
+            reactions,
+        });
+        Some(())
+    }
+
+    let mut stock = available.clone();
+    let mut steps = Vec::new();
+    plan(target, amount, &mut stock, &mut steps)?;
+    Some(steps)
+}
+
 impl ResourceType {
+    /// Finds the compound a lab reaction between `a` and `b` produces, in
+    /// either order, or `None` if they don't react.
+    ///
+    /// This is the inverse of [`ResourceType::reaction_components`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use screeps::ResourceType;
+    ///
+    /// assert_eq!(
+    ///     ResourceType::reaction_product(ResourceType::Hydrogen, ResourceType::Oxygen),
+    ///     Some(ResourceType::Hydroxide)
+    /// );
+    /// assert_eq!(
+    ///     ResourceType::reaction_product(ResourceType::Energy, ResourceType::Oxygen),
+    ///     None
+    /// );
+    /// ```
+    pub fn reaction_product(a: ResourceType, b: ResourceType) -> Option<ResourceType> {
+        ResourceType::into_enum_iter().find(|product| match product.reaction_components() {
+            Some([x, y]) => (x, y) == (a, b) || (x, y) == (b, a),
+            None => false,
+        })
+    }
+
     /// Translates the `REACTIONS` constant.
     #[inline]
     pub fn reaction_components(self) -> Option<[ResourceType; 2]> {
@@ -739,3 +847,94 @@ impl ResourceType {
         Some(recipe)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use enum_iterator::IntoEnumIterator;
+
+    use super::reaction_plan;
+    use crate::constants::ResourceType::{self, *};
+
+    #[test]
+    fn reaction_plan_orders_dependencies() {
+        let available: HashMap<ResourceType, u32> = [
+            (Oxygen, 10_000),
+            (Hydrogen, 10_000),
+            (Utrium, 10_000),
+            (Lemergium, 10_000),
+            (Zynthium, 10_000),
+            (Keanium, 10_000),
+            (Catalyst, 10_000),
+        ]
+        .iter()
+        .copied()
+        .collect();
+
+        let steps = reaction_plan(CatalyzedGhodiumAcid, &available, 3000).unwrap();
+        assert_eq!(steps.last().unwrap().product, CatalyzedGhodiumAcid);
+        assert_eq!(steps.last().unwrap().amount, 3000);
+        assert_eq!(steps.last().unwrap().reactions, 600);
+
+        for (idx, step) in steps.iter().enumerate() {
+            for input in &step.inputs {
+                assert!(
+                    available.contains_key(input)
+                        || steps[..idx].iter().any(|s| s.product == *input),
+                    "{:?} needed before it's produced",
+                    input
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reaction_plan_uses_stock_and_detects_shortage() {
+        let mut available = HashMap::new();
+        available.insert(Hydroxide, 100);
+        assert_eq!(reaction_plan(Hydroxide, &available, 100), Some(vec![]));
+        assert_eq!(reaction_plan(Hydroxide, &available, 105), None);
+
+        available.insert(Oxygen, 5);
+        available.insert(Hydrogen, 5);
+        let steps = reaction_plan(Hydroxide, &available, 102).unwrap();
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].amount, 5);
+    }
+
+    #[test]
+    fn mineral_taxonomy() {
+        for &mineral in ResourceType::base_minerals() {
+            assert!(mineral.is_base_mineral());
+            assert!(!mineral.is_compound());
+        }
+        assert!(Ghodium.is_compound() && !Ghodium.is_boost());
+        assert!(Hydroxide.is_compound() && !Hydroxide.is_boost());
+        assert!(CatalyzedUtriumAcid.is_compound() && CatalyzedUtriumAcid.is_boost());
+        assert!(!Energy.is_base_mineral() && !Energy.is_compound() && !Energy.is_boost());
+    }
+
+    #[test]
+    fn reaction_components_cover_all_reactions() {
+        assert_eq!(Hydroxide.reaction_components(), Some([Oxygen, Hydrogen]));
+        assert_eq!(
+            CatalyzedUtriumAcid.reaction_components(),
+            Some([UtriumAcid, Catalyst])
+        );
+        assert_eq!(Ops.reaction_components(), None);
+        assert_eq!(Energy.reaction_components(), None);
+
+        for ty in ResourceType::into_enum_iter() {
+            assert_eq!(
+                ty.reaction_components().is_some(),
+                ty.reaction_time().is_some(),
+                "{:?}",
+                ty
+            );
+            if let Some([a, b]) = ty.reaction_components() {
+                assert_eq!(ResourceType::reaction_product(b, a), Some(ty));
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Constant tables which can vary between server rulesets.
+//!
+//! Public functions such as [`Part::cost`], [`controller_levels`] and
+//! [`extension_energy_capacity`] dispatch to these tables. Only the official
+//! MMO values are provided for now; a ruleset with different values would add
+//! its own tables here, selected by a cargo feature.
+//!
+//! [`Part::cost`]: crate::constants::Part::cost
+//! [`controller_levels`]: crate::constants::controller_levels
+//! [`extension_energy_capacity`]: crate::constants::extension_energy_capacity
+
+use crate::constants::Part;
+
+#[inline]
+pub(crate) fn body_part_cost(part: Part) -> u32 {
+    match part {
+        Part::Move => 50,
+        Part::Work => 100,
+        Part::Carry => 50,
+        Part::Attack => 80,
+        Part::RangedAttack => 150,
+        Part::Tough => 10,
+        Part::Heal => 250,
+        Part::Claim => 600,
+    }
+}
+
+#[inline]
+pub(crate) fn controller_levels(current_rcl: u32) -> Option<u32> {
+    match current_rcl {
+        1 => Some(200),
+        2 => Some(45_000),
+        3 => Some(135_000),
+        4 => Some(405_000),
+        5 => Some(1_215_000),
+        6 => Some(3_645_000),
+        7 => Some(10_935_000),
+        _ => None,
+    }
+}
+
+#[inline]
+pub(crate) fn extension_energy_capacity(rcl: u32) -> u32 {
+    match rcl {
+        r if r < 7 => 50,
+        7 => 100,
+        _ => 200,
+    }
+}
@@ -3,6 +3,7 @@ use std::{borrow::Cow, fmt, str::FromStr};
 
 use enum_iterator::IntoEnumIterator;
 use num_derive::FromPrimitive;
+use num_traits::FromPrimitive as _;
 use parse_display::FromStr;
 use serde::{
     de::{Deserializer, Error as _, Unexpected},
@@ -70,6 +71,87 @@ pub enum Direction {
 
 js_deserializable!(Direction);
 
+impl Direction {
+    /// The `(dx, dy)` step this direction points in, with `y` increasing
+    /// downwards.
+    ///
+    /// ```
+    /// use screeps::Direction;
+    ///
+    /// assert_eq!(Direction::Top.offset(), (0, -1));
+    /// assert_eq!(Direction::BottomLeft.offset(), (-1, 1));
+    /// ```
+    #[inline]
+    pub fn offset(self) -> (i8, i8) {
+        match self {
+            Direction::Top => (0, -1),
+            Direction::TopRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::BottomRight => (1, 1),
+            Direction::Bottom => (0, 1),
+            Direction::BottomLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::TopLeft => (-1, -1),
+        }
+    }
+
+    /// The direction of a step by `(dx, dy)`, using only the sign of each
+    /// component, or `None` when both are 0.
+    ///
+    /// ```
+    /// use screeps::Direction;
+    ///
+    /// assert_eq!(Direction::from_offset(5, -3), Some(Direction::TopRight));
+    /// assert_eq!(Direction::from_offset(0, 0), None);
+    /// ```
+    pub fn from_offset(dx: i32, dy: i32) -> Option<Direction> {
+        let dir = match (dx.signum(), dy.signum()) {
+            (0, -1) => Direction::Top,
+            (1, -1) => Direction::TopRight,
+            (1, 0) => Direction::Right,
+            (1, 1) => Direction::BottomRight,
+            (0, 1) => Direction::Bottom,
+            (-1, 1) => Direction::BottomLeft,
+            (-1, 0) => Direction::Left,
+            (-1, -1) => Direction::TopLeft,
+            _ => return None,
+        };
+        Some(dir)
+    }
+
+    /// Rotates this direction clockwise by `steps` eighths of a turn,
+    /// wrapping around the compass.
+    ///
+    /// ```
+    /// use screeps::Direction::*;
+    ///
+    /// assert_eq!(Top.rotate_clockwise(1), TopRight);
+    /// assert_eq!(Left.rotate_clockwise(3), TopRight);
+    /// assert_eq!(TopLeft.rotate_clockwise(1), Top);
+    /// assert_eq!(Bottom.rotate_clockwise(8), Bottom);
+    /// ```
+    #[inline]
+    pub fn rotate_clockwise(self, steps: u8) -> Direction {
+        let index = (self as u8 - 1 + steps % 8) % 8;
+        Direction::from_u8(index + 1).expect("expected direction index in 1..=8")
+    }
+
+    /// Rotates this direction counter-clockwise by `steps` eighths of a turn,
+    /// wrapping around the compass.
+    ///
+    /// ```
+    /// use screeps::Direction::*;
+    ///
+    /// assert_eq!(Top.rotate_counter_clockwise(1), TopLeft);
+    /// assert_eq!(Right.rotate_counter_clockwise(2), Top);
+    /// assert_eq!(Bottom.rotate_counter_clockwise(12), Top);
+    /// ```
+    #[inline]
+    pub fn rotate_counter_clockwise(self, steps: u8) -> Direction {
+        self.rotate_clockwise(8 - steps % 8)
+    }
+}
+
 impl ::std::ops::Neg for Direction {
     type Output = Direction;
 
@@ -139,6 +221,62 @@ pub enum ExitDirection {
     Left = Direction::Left as u8,
 }
 
+impl ExitDirection {
+    /// Converts a [`Direction`] to the exit it points toward, or `None` for
+    /// diagonal directions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use screeps::{Direction, ExitDirection};
+    ///
+    /// assert_eq!(
+    ///     ExitDirection::from_direction(Direction::Left),
+    ///     Some(ExitDirection::Left)
+    /// );
+    /// assert_eq!(ExitDirection::from_direction(Direction::TopLeft), None);
+    /// ```
+    #[inline]
+    pub fn from_direction(dir: Direction) -> Option<Self> {
+        match dir {
+            Direction::Top => Some(ExitDirection::Top),
+            Direction::Right => Some(ExitDirection::Right),
+            Direction::Bottom => Some(ExitDirection::Bottom),
+            Direction::Left => Some(ExitDirection::Left),
+            _ => None,
+        }
+    }
+
+    /// Decodes the result of `Room.findExitTo` or `Game.map.findExit`: exit
+    /// constants become `Ok`, negative error codes become `Err`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code` is neither an exit constant nor a [`ReturnCode`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use screeps::{ExitDirection, ReturnCode};
+    ///
+    /// assert_eq!(ExitDirection::from_find_exit_code(3), Ok(ExitDirection::Right));
+    /// assert_eq!(
+    ///     ExitDirection::from_find_exit_code(-2),
+    ///     Err(ReturnCode::NoPath)
+    /// );
+    /// ```
+    pub fn from_find_exit_code(code: i32) -> Result<Self, ReturnCode> {
+        if code < 0 {
+            Err(ReturnCode::from_i32(code).unwrap_or_else(|| {
+                panic!("find exit error code {} is not a valid ReturnCode", code)
+            }))
+        } else {
+            Ok(ExitDirection::from_i32(code)
+                .unwrap_or_else(|| panic!("find exit code {} is not a valid exit", code)))
+        }
+    }
+}
+
 impl From<ExitDirection> for find::Exit {
     #[inline]
     fn from(dir: ExitDirection) -> Self {
@@ -236,7 +374,18 @@ js_deserializable!(Terrain);
 /// [`Part::deserialize_from_str`].
 ///
 /// See the [module-level documentation][crate::constants] for more details.
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize_repr, Deserialize_repr, FromStr)]
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Hash,
+    Serialize_repr,
+    Deserialize_repr,
+    FromStr,
+    IntoEnumIterator,
+)]
 #[repr(u8)]
 #[display(style = "snake_case")]
 pub enum Part {
@@ -251,18 +400,58 @@ pub enum Part {
 }
 
 impl Part {
+    /// Every body part type, in the order of the `BODYPARTS_ALL` constant.
+    ///
+    /// ```
+    /// use screeps::Part;
+    ///
+    /// let total: u32 = Part::all().iter().map(|p| p.cost()).sum();
+    /// assert_eq!(total, 1290);
+    /// ```
+    #[inline]
+    pub fn all() -> [Part; 8] {
+        [
+            Part::Move,
+            Part::Work,
+            Part::Carry,
+            Part::Attack,
+            Part::RangedAttack,
+            Part::Tough,
+            Part::Heal,
+            Part::Claim,
+        ]
+    }
+
     /// Translates the `BODYPART_COST` constant.
     #[inline]
     pub fn cost(self) -> u32 {
+        super::server::body_part_cost(self)
+    }
+
+    /// This part's cost according to a custom cost table, for servers which
+    /// rebalance part costs at runtime.
+    ///
+    /// ```
+    /// use screeps::{Part, PartCostConfig};
+    ///
+    /// let config = PartCostConfig {
+    ///     work: 80,
+    ///     ..PartCostConfig::default()
+    /// };
+    /// assert_eq!(Part::Work.cost_with(&config), 80);
+    /// assert_eq!(Part::Move.cost_with(&config), Part::Move.cost());
+    /// ```
+    #[inline]
+    pub fn cost_with(self, config: &PartCostConfig) -> u32 {
         match self {
-            Part::Move => 50,
-            Part::Work => 100,
-            Part::Carry => 50,
-            Part::Attack => 80,
-            Part::RangedAttack => 150,
-            Part::Tough => 10,
-            Part::Heal => 250,
-            Part::Claim => 600,
+            Part::Move => config.move_,
+            Part::Work => config.work,
+            Part::Carry => config.carry,
+            Part::Attack => config.attack,
+            Part::RangedAttack => config.ranged_attack,
+            Part::Tough => config.tough,
+            Part::Heal => config.heal,
+            Part::Claim => config.claim,
         }
     }
 
@@ -281,6 +470,58 @@ impl Part {
 
 js_deserializable!(Part);
 
+/// Total energy cost of spawning a creep with the given body, summing
+/// [`Part::cost`].
+///
+/// ```
+/// use screeps::{body_cost, Part};
+///
+/// assert_eq!(body_cost(&[Part::Work, Part::Carry, Part::Move]), 200);
+/// assert_eq!(body_cost(&[]), 0);
+/// ```
+pub fn body_cost(parts: &[Part]) -> u32 {
+    parts.iter().map(|p| p.cost()).sum()
+}
+
+/// A table of body part costs, for servers whose costs differ from the
+/// official servers'.
+///
+/// The [`Default`] holds the costs from [`Part::cost`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PartCostConfig {
+    #[serde(rename = "move")]
+    pub move_: u32,
+    pub work: u32,
+    pub carry: u32,
+    pub attack: u32,
+    pub ranged_attack: u32,
+    pub tough: u32,
+    pub heal: u32,
+    pub claim: u32,
+}
+
+impl Default for PartCostConfig {
+    fn default() -> Self {
+        PartCostConfig {
+            move_: Part::Move.cost(),
+            work: Part::Work.cost(),
+            carry: Part::Carry.cost(),
+            attack: Part::Attack.cost(),
+            ranged_attack: Part::RangedAttack.cost(),
+            tough: Part::Tough.cost(),
+            heal: Part::Heal.cost(),
+            claim: Part::Claim.cost(),
+        }
+    }
+}
+
+impl PartCostConfig {
+    /// Total cost of a body using this table, like [`body_cost`].
+    pub fn body_cost(&self, parts: &[Part]) -> u32 {
+        parts.iter().map(|p| p.cost_with(self)).sum()
+    }
+}
+
 /// Translates the `DENSITY_*` constants.
 #[derive(
     Debug,
@@ -355,3 +596,83 @@ impl Density {
 pub enum PowerClass {
     Operator,
 }
+
+#[cfg(test)]
+mod test {
+    use super::Direction::{self, *};
+
+    #[test]
+    fn direction_offsets() {
+        let expected = [
+            (Top, (0, -1)),
+            (TopRight, (1, -1)),
+            (Right, (1, 0)),
+            (BottomRight, (1, 1)),
+            (Bottom, (0, 1)),
+            (BottomLeft, (-1, 1)),
+            (Left, (-1, 0)),
+            (TopLeft, (-1, -1)),
+        ];
+        for &(dir, offset) in &expected {
+            assert_eq!(dir.offset(), offset);
+            let (dx, dy) = (-dir).offset();
+            assert_eq!((-dx, -dy), offset, "-{:?}", dir);
+        }
+    }
+
+    #[test]
+    fn direction_from_offset() {
+        assert_eq!(Direction::from_offset(0, -1), Some(Top));
+        assert_eq!(Direction::from_offset(7, 0), Some(Right));
+        assert_eq!(Direction::from_offset(0, 20), Some(Bottom));
+        assert_eq!(Direction::from_offset(-2, 0), Some(Left));
+        assert_eq!(Direction::from_offset(5, -3), Some(TopRight));
+        assert_eq!(Direction::from_offset(-1, -49), Some(TopLeft));
+        assert_eq!(Direction::from_offset(3, 3), Some(BottomRight));
+        assert_eq!(Direction::from_offset(-4, 1), Some(BottomLeft));
+        assert_eq!(Direction::from_offset(0, 0), None);
+
+        for dir in [
+            Top,
+            TopRight,
+            Right,
+            BottomRight,
+            Bottom,
+            BottomLeft,
+            Left,
+            TopLeft,
+        ] {
+            let (dx, dy) = dir.offset();
+            assert_eq!(
+                Direction::from_offset(i32::from(dx), i32::from(dy)),
+                Some(dir)
+            );
+        }
+    }
+
+    #[test]
+    fn custom_part_costs() {
+        use super::{body_cost, Part, PartCostConfig};
+
+        let default = PartCostConfig::default();
+        let body = [Part::Work, Part::Work, Part::Carry, Part::Move];
+        assert_eq!(default.body_cost(&body), body_cost(&body));
+
+        let config = PartCostConfig {
+            work: 80,
+            ..default
+        };
+        assert_eq!(config.body_cost(&body), 80 * 2 + 50 + 50);
+    }
+
+    #[test]
+    fn part_all_lists_every_variant() {
+        use enum_iterator::IntoEnumIterator;
+
+        use super::Part;
+
+        let all = Part::all();
+        assert_eq!(all.len(), Part::VARIANT_COUNT);
+        assert!(Part::into_enum_iter().eq(all.iter().copied()));
+    }
+}
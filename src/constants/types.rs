@@ -1,6 +1,7 @@
 //! `*Type` constants.
 use std::{borrow::Cow, str::FromStr};
 
+use enum_iterator::IntoEnumIterator;
 use num_derive::FromPrimitive;
 use parse_display::{Display, FromStr};
 use serde::{
@@ -9,6 +10,14 @@ use serde::{
 };
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
+use super::{
+    numbers::{
+        ATTACK_POWER, BUILD_POWER, CARRY_CAPACITY, DISMANTLE_POWER, HARVEST_POWER, HEAL_POWER,
+        RANGED_ATTACK_POWER, UPGRADE_CONTROLLER_POWER,
+    },
+    small_enums::Part,
+};
+
 /// Translates `STRUCTURE_*` constants.
 ///
 /// *Note:* This constant's `TryFrom<Value>`, `Serialize` and `Deserialize`
@@ -256,7 +265,17 @@ js_deserializable!(IntershardResourceType);
 ///
 /// See the [module-level documentation][crate::constants] for more details.
 #[derive(
-    Copy, Clone, Debug, Display, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr, FromStr,
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize_repr,
+    Deserialize_repr,
+    FromStr,
+    IntoEnumIterator,
 )]
 #[repr(u16)]
 pub enum ResourceType {
@@ -518,6 +537,11 @@ pub enum ResourceType {
     Essence = 84,
 }
 
+/// The effect of a boost compound, from [`ResourceType::boost`], holding the
+/// multiplier it applies.
+///
+/// For [`Boost::Tough`] the multiplier applies to damage taken, so it is
+/// below 1.
 #[derive(Copy, Clone, Debug)]
 pub enum Boost {
     Harvest(f64),
@@ -532,7 +556,133 @@ pub enum Boost {
     Tough(f64),
 }
 
+impl Boost {
+    /// The body part this boost applies to.
+    ///
+    /// ```
+    /// use screeps::{Boost, Part};
+    ///
+    /// assert_eq!(Boost::Dismantle(2.0).part(), Part::Work);
+    /// ```
+    pub fn part(self) -> Part {
+        match self {
+            Boost::Harvest(_)
+            | Boost::BuildAndRepair(_)
+            | Boost::Dismantle(_)
+            | Boost::UpgradeController(_) => Part::Work,
+            Boost::Attack(_) => Part::Attack,
+            Boost::RangedAttack(_) => Part::RangedAttack,
+            Boost::Heal(_) => Part::Heal,
+            Boost::Carry(_) => Part::Carry,
+            Boost::Move(_) => Part::Move,
+            Boost::Tough(_) => Part::Tough,
+        }
+    }
+
+    /// The multiplier this boost applies.
+    pub fn multiplier(self) -> f64 {
+        match self {
+            Boost::Harvest(m)
+            | Boost::BuildAndRepair(m)
+            | Boost::Dismantle(m)
+            | Boost::UpgradeController(m)
+            | Boost::Attack(m)
+            | Boost::RangedAttack(m)
+            | Boost::Heal(m)
+            | Boost::Carry(m)
+            | Boost::Move(m)
+            | Boost::Tough(m) => m,
+        }
+    }
+}
+
+/// Per-tick output of `count` active parts of type `part`, with the
+/// multiplier from `boost` applied if it boosts that part.
+///
+/// Output is hits of damage for [`Part::Attack`] and [`Part::RangedAttack`],
+/// hits healed (adjacent) for [`Part::Heal`] and capacity for [`Part::Carry`].
+///
+/// A [`Part::Work`] part's output depends on the action its boost improves:
+/// [`HARVEST_POWER`] for harvest boosts and unboosted parts,
+/// [`BUILD_POWER`] for build boosts, [`DISMANTLE_POWER`] for dismantle boosts
+/// and [`UPGRADE_CONTROLLER_POWER`] for upgrade boosts.
+///
+/// Parts with no direct output ([`Part::Move`], [`Part::Tough`] and
+/// [`Part::Claim`]) return 0. Boosts for a different part are ignored.
+///
+/// ```
+/// use screeps::{boosted_output, Part, ResourceType};
+///
+/// assert_eq!(boosted_output(Part::Attack, 4, None), 120);
+/// assert_eq!(
+///     boosted_output(Part::Attack, 4, Some(ResourceType::UtriumAcid)),
+///     360
+/// );
+/// assert_eq!(
+///     boosted_output(Part::Work, 2, Some(ResourceType::LemergiumHydride)),
+///     15
+/// );
+/// ```
+pub fn boosted_output(part: Part, count: u32, boost: Option<ResourceType>) -> u32 {
+    let boost = boost.and_then(ResourceType::boost);
+    let (base, multiplier) = match (part, boost) {
+        (Part::Attack, Some(Boost::Attack(m))) => (ATTACK_POWER, m),
+        (Part::Attack, _) => (ATTACK_POWER, 1.0),
+        (Part::RangedAttack, Some(Boost::RangedAttack(m))) => (RANGED_ATTACK_POWER, m),
+        (Part::RangedAttack, _) => (RANGED_ATTACK_POWER, 1.0),
+        (Part::Heal, Some(Boost::Heal(m))) => (HEAL_POWER, m),
+        (Part::Heal, _) => (HEAL_POWER, 1.0),
+        (Part::Carry, Some(Boost::Carry(m))) => (CARRY_CAPACITY, m),
+        (Part::Carry, _) => (CARRY_CAPACITY, 1.0),
+        (Part::Work, Some(Boost::Harvest(m))) => (HARVEST_POWER, m),
+        (Part::Work, Some(Boost::BuildAndRepair(m))) => (BUILD_POWER, m),
+        (Part::Work, Some(Boost::Dismantle(m))) => (DISMANTLE_POWER, m),
+        (Part::Work, Some(Boost::UpgradeController(m))) => (UPGRADE_CONTROLLER_POWER, m),
+        (Part::Work, _) => (HARVEST_POWER, 1.0),
+        (Part::Move, _) | (Part::Tough, _) | (Part::Claim, _) => (0, 1.0),
+    };
+
+    (f64::from(base * count) * multiplier).floor() as u32
+}
+
 impl ResourceType {
+    /// The seven base minerals which can be mined: hydrogen, oxygen,
+    /// utrium, lemergium, keanium, zynthium and catalyst.
+    pub fn base_minerals() -> &'static [ResourceType] {
+        use ResourceType::*;
+        &[
+            Hydrogen, Oxygen, Utrium, Lemergium, Keanium, Zynthium, Catalyst,
+        ]
+    }
+
+    /// Whether this is one of the [base minerals][Self::base_minerals].
+    pub fn is_base_mineral(self) -> bool {
+        Self::base_minerals().contains(&self)
+    }
+
+    /// Whether this is produced by a lab reaction, including intermediate
+    /// compounds like hydroxide and ghodium as well as boosts.
+    pub fn is_compound(self) -> bool {
+        self.reaction_components().is_some()
+    }
+
+    /// Whether this can boost creep parts.
+    pub fn is_boost(self) -> bool {
+        self.boost().is_some()
+    }
+
+    /// The body part this compound boosts, if it is a boost.
+    ///
+    /// ```
+    /// use screeps::{Part, ResourceType};
+    ///
+    /// assert_eq!(ResourceType::UtriumHydride.boosted_part(), Some(Part::Attack));
+    /// assert_eq!(ResourceType::Energy.boosted_part(), None);
+    /// ```
+    pub fn boosted_part(self) -> Option<Part> {
+        self.boost().map(Boost::part)
+    }
+
     /// Translates the `BOOSTS` constant.
     #[inline]
     pub fn boost(self) -> Option<Boost> {
@@ -938,3 +1088,22 @@ impl<'de> Deserialize<'de> for EffectType {
         Ok(effect_type)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use enum_iterator::IntoEnumIterator;
+
+    use super::ResourceType;
+
+    #[test]
+    fn resource_type_strings_round_trip() {
+        assert_eq!(ResourceType::Energy.to_string(), "energy");
+        assert_eq!(ResourceType::CatalyzedGhodiumAlkalide.to_string(), "XGHO2");
+        assert_eq!("H".parse(), Ok(ResourceType::Hydrogen));
+        assert!("hydrogen".parse::<ResourceType>().is_err());
+
+        for ty in ResourceType::into_enum_iter() {
+            assert_eq!(ty.to_string().parse(), Ok(ty));
+        }
+    }
+}
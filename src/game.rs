@@ -15,8 +15,13 @@ pub mod cpu;
 pub mod gcl;
 pub mod gpl;
 pub mod map;
+pub mod map_visual;
 pub mod market;
 pub mod shards;
+mod throttle;
+mod tick_rng;
+
+pub use self::{throttle::Throttle, tick_rng::TickRng};
 
 /// See [http://docs.screeps.com/api/#Game.constructionSites]
 ///
@@ -44,6 +49,12 @@ pub mod flags {
 /// [http://docs.screeps.com/api/#Game.powerCreeps]: http://docs.screeps.com/api/#Game.powerCreeps
 pub mod power_creeps {
     game_map_access!(objects::AccountPowerCreep, Game.powerCreeps);
+
+    /// Retrieve all power creeps on the account which aren't currently
+    /// spawned on any shard.
+    pub fn unspawned() -> Vec<objects::AccountPowerCreep> {
+        values().into_iter().filter(|pc| !pc.is_spawned()).collect()
+    }
 }
 
 /// See [http://docs.screeps.com/api/#Game.resources]
@@ -80,6 +91,61 @@ pub mod resources {
     pub fn get(key: IntershardResourceType) -> Option<u32> {
         js_unwrap!(Game.resources[__resource_type_num_to_str(@{key as u32})])
     }
+
+    /// Number of [`Pixel`]s owned, or 0 if none.
+    ///
+    /// [`Pixel`]: IntershardResourceType::Pixel
+    pub fn pixels() -> u32 {
+        get(IntershardResourceType::Pixel).unwrap_or(0)
+    }
+
+    /// Market credits owned; these live in `Game.market` rather than
+    /// `Game.resources`.
+    ///
+    /// Equivalent to [`game::market::credits`][crate::game::market::credits].
+    pub fn credits() -> f64 {
+        crate::game::market::credits()
+    }
+}
+
+/// Level and progress toward the next level of a global level, such as
+/// [`gcl()`] or [`gpl()`].
+///
+/// Progress values are rounded down to whole points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LevelProgress {
+    /// The current level.
+    pub level: u32,
+    /// Progress toward the next level.
+    pub progress: u64,
+    /// Progress required to reach the next level.
+    pub progress_total: u64,
+}
+
+/// Retrieves the full state of [`Game.gcl`] at once.
+///
+/// See also the individual getters in [`gcl`][mod@gcl].
+///
+/// [`Game.gcl`]: http://docs.screeps.com/api/#Game.gcl
+pub fn gcl() -> LevelProgress {
+    LevelProgress {
+        level: gcl::level(),
+        progress: gcl::progress() as u64,
+        progress_total: gcl::progress_total() as u64,
+    }
+}
+
+/// Retrieves the full state of [`Game.gpl`] at once.
+///
+/// See also the individual getters in [`gpl`][mod@gpl].
+///
+/// [`Game.gpl`]: http://docs.screeps.com/api/#Game.gpl
+pub fn gpl() -> LevelProgress {
+    LevelProgress {
+        level: gpl::level(),
+        progress: gpl::progress() as u64,
+        progress_total: gpl::progress_total() as u64,
+    }
 }
 
 /// See [http://docs.screeps.com/api/#Game.rooms]
@@ -144,6 +210,16 @@ pub fn time() -> u32 {
     js_unwrap!(Game.time)
 }
 
+/// Creates a random number generator seeded from the current tick and
+/// `extra_seed`.
+///
+/// Every call in the same tick with the same `extra_seed` produces the same
+/// sequence, while different ticks produce different sequences, unlike
+/// `Math.random`, which can't be reproduced.
+pub fn rng(extra_seed: u64) -> TickRng {
+    TickRng::from_seed((u64::from(time()) << 32) ^ extra_seed)
+}
+
 /// See [http://docs.screeps.com/api/#Game.getObjectById]
 ///
 /// This gets an object expecting a specific type and will return a
@@ -195,6 +271,21 @@ where
     .try_into()
 }
 
+/// See [http://docs.screeps.com/api/#Game.getObjectById]
+///
+/// Like [`get_object_typed`], but returns `None` both when no object has this
+/// id and when the object is not actually a `T` (for instance when a stored
+/// id now belongs to a different kind of object).
+///
+/// The type is checked with an `instanceof` test against the object's
+/// JavaScript class.
+pub fn get_object_by_id<T>(id: ObjectId<T>) -> Option<T>
+where
+    T: HasId + SizedRoomObject,
+{
+    get_object_typed(id).ok().flatten()
+}
+
 /// See [http://docs.screeps.com/api/#Game.getObjectById]
 ///
 /// This gets the object in 'erased' form - all that is known about it is that
@@ -212,6 +303,24 @@ pub fn get_object_erased(id: impl Into<RawObjectId>) -> Option<RoomObject> {
     js_unwrap_ref!(Game.getObjectById(object_id_from_packed(@{array_view})))
 }
 
+/// Whether the object with the given id currently exists and is visible.
+///
+/// This only checks for existence, ignoring the type of the id. Objects in
+/// rooms without visibility are reported as not alive.
+pub fn is_alive<T>(id: &ObjectId<T>) -> bool {
+    let id: RawObjectId = (*id).into();
+    let array_view = unsafe { id.unsafe_as_uploaded() };
+    js_unwrap!(Game.getObjectById(object_id_from_packed(@{array_view})) != null)
+}
+
+/// Removes every id from `ids` which doesn't refer to a currently visible
+/// object, keeping the order of the rest.
+///
+/// See [`is_alive`].
+pub fn retain_alive<T>(ids: &mut Vec<ObjectId<T>>) {
+    ids.retain(is_alive);
+}
+
 pub fn notify(message: &str, group_interval: Option<u32>) {
     js! { @(no_return)
         Game.notify(@{message}, @{group_interval.unwrap_or(0)});
@@ -5,7 +5,10 @@ use std::collections;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{constants::ReturnCode, traits::TryInto};
+use crate::{
+    constants::{ReturnCode, CPU_BUCKET_MAX},
+    traits::TryInto,
+};
 
 /// See [`v8_getheapstatistics`]
 ///
@@ -135,3 +138,44 @@ pub fn generate_pixel() -> ReturnCode {
     // undefined on private servers, return OK in that case
     js_unwrap!(typeof(Game.cpu.generatePixel) == "function" && Game.cpu.generatePixel() || 0)
 }
+
+/// Generate a [`Pixel`] only if [`game::cpu::bucket`] is full, at
+/// [`CPU_BUCKET_MAX`], returning `None` without generating otherwise.
+///
+/// [`Pixel`]: crate::constants::IntershardResourceType::Pixel
+/// [`CPU_BUCKET_MAX`]: crate::constants::CPU_BUCKET_MAX
+/// [`game::cpu::bucket`]: crate::game::cpu::bucket
+pub fn generate_pixel_if_bucket_full() -> Option<ReturnCode> {
+    if bucket() >= CPU_BUCKET_MAX {
+        Some(generate_pixel())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HeapStatistics;
+
+    #[test]
+    fn heap_statistics_decode_v8_fields() {
+        let stats: HeapStatistics = serde_json::from_str(
+            r#"{
+                "total_heap_size": 39845888,
+                "total_heap_size_executable": 3670016,
+                "total_physical_size": 38526312,
+                "total_available_size": 281231184,
+                "used_heap_size": 33124680,
+                "heap_size_limit": 318767104,
+                "malloced_memory": 8192,
+                "peak_malloced_memory": 1048576,
+                "does_zap_garbage": 0,
+                "externally_allocated_size": 2101944
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(stats.used_heap_size, 33_124_680);
+        assert_eq!(stats.heap_size_limit, 318_767_104);
+        assert_eq!(stats.externally_allocated_size, 2_101_944);
+    }
+}
@@ -98,8 +98,7 @@ impl<'de> Deserialize<'de> for RoomStatus {
 /// Implements `Game.map.findExit`.
 pub fn find_exit(from_room: RoomName, to_room: RoomName) -> Result<ExitDirection, ReturnCode> {
     let code: i32 = js_unwrap! {Game.map.findExit(@{from_room}, @{to_room})};
-    ExitDirection::from_i32(code)
-        .ok_or_else(|| ReturnCode::from_i32(code).expect("find_exit: Error code not recognized."))
+    ExitDirection::from_find_exit_code(code)
 }
 
 pub fn find_exit_with_callback(
@@ -134,15 +133,7 @@ pub fn find_exit_with_callback(
     .try_into()
     .expect("expected int from findExit");
 
-    ExitDirection::from_i32(code)
-        .map(Ok)
-        .or_else(|| ReturnCode::from_i32(code).map(Err))
-        .unwrap_or_else(|| {
-            panic!(
-                "find_exit: return value {:?} not recognized as either Exit nor ReturnCode",
-                code
-            )
-        })
+    ExitDirection::from_find_exit_code(code)
 }
 
 pub fn find_route(from_room: &str, to_room: &str) -> Result<Vec<RoomRouteStep>, ReturnCode> {
@@ -199,6 +190,65 @@ fn parse_find_route_returned_value(v: Value) -> Result<Vec<RoomRouteStep>, Retur
     }
 }
 
+/// Finds the route crossing the fewest rooms from `from` to `to`, never
+/// entering rooms for which `avoid` returns true.
+///
+/// Runs a breadth-first search over the room graph given by
+/// [`describe_exits`], so room costs and room status aren't considered unless
+/// `avoid` checks them. `avoid` isn't called for `from` or `to`.
+///
+/// Like [`find_route`], the returned rooms exclude `from` and end with `to`.
+/// Returns `None` if `to` can't be reached.
+pub fn bfs_route(
+    from: RoomName,
+    to: RoomName,
+    avoid: impl Fn(RoomName) -> bool,
+) -> Option<Vec<RoomName>> {
+    bfs_route_with_exits(from, to, avoid, |room| {
+        let mut exits: Vec<(Direction, RoomName)> = describe_exits(room).into_iter().collect();
+        exits.sort_by_key(|&(dir, _)| dir as u8);
+        exits.into_iter().map(|(_, room)| room).collect()
+    })
+}
+
+fn bfs_route_with_exits(
+    from: RoomName,
+    to: RoomName,
+    avoid: impl Fn(RoomName) -> bool,
+    mut exits: impl FnMut(RoomName) -> Vec<RoomName>,
+) -> Option<Vec<RoomName>> {
+    let mut came_from = collections::HashMap::new();
+    let mut queue = collections::VecDeque::new();
+    came_from.insert(from, from);
+    queue.push_back(from);
+
+    while let Some(room) = queue.pop_front() {
+        if room == to {
+            let mut route = vec![to];
+            let mut current = to;
+            while came_from[&current] != from {
+                current = came_from[&current];
+                route.push(current);
+            }
+            if to == from {
+                route.clear();
+            }
+            route.reverse();
+            return Some(route);
+        }
+
+        for next in exits(room) {
+            if came_from.contains_key(&next) || (next != to && avoid(next)) {
+                continue;
+            }
+            came_from.insert(next, room);
+            queue.push_back(next);
+        }
+    }
+
+    None
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RoomRouteStep {
@@ -206,3 +256,48 @@ pub struct RoomRouteStep {
     pub room: RoomName,
 }
 js_deserializable!(RoomRouteStep);
+
+#[cfg(test)]
+mod test {
+    use super::bfs_route_with_exits;
+    use crate::local::RoomName;
+
+    fn room(x: i32, y: i32) -> RoomName {
+        RoomName::new("W0N0").unwrap() + (x, y)
+    }
+
+    /// Exits for an open 5x5 grid of rooms.
+    fn grid_exits(r: RoomName) -> Vec<RoomName> {
+        let (x, y) = r - room(0, 0);
+        let mut exits = Vec::new();
+        for &(dx, dy) in &[(0, -1), (1, 0), (0, 1), (-1, 0)] {
+            let (nx, ny) = (x + dx, y + dy);
+            if (0..5).contains(&nx) && (0..5).contains(&ny) {
+                exits.push(room(nx, ny));
+            }
+        }
+        exits
+    }
+
+    #[test]
+    fn bfs_route_avoids_rooms() {
+        let direct = bfs_route_with_exits(room(0, 0), room(2, 0), |_| false, grid_exits);
+        assert_eq!(direct, Some(vec![room(1, 0), room(2, 0)]));
+
+        let around =
+            bfs_route_with_exits(room(0, 0), room(2, 0), |r| r == room(1, 0), grid_exits).unwrap();
+        assert_eq!(around.len(), 4);
+        assert!(!around.contains(&room(1, 0)));
+
+        let wall = |r: RoomName| (r - room(0, 0)).0 == 1;
+        assert_eq!(
+            bfs_route_with_exits(room(0, 0), room(2, 0), wall, grid_exits),
+            None
+        );
+
+        assert_eq!(
+            bfs_route_with_exits(room(0, 0), room(0, 0), |_| false, grid_exits),
+            Some(vec![])
+        );
+    }
+}
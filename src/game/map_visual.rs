@@ -0,0 +1,66 @@
+//! See [http://docs.screeps.com/api/#Game-map-visual]
+//!
+//! [http://docs.screeps.com/api/#Game-map-visual]: http://docs.screeps.com/api/#Game-map-visual
+use crate::{
+    local::{Position, RoomName},
+    objects::PolyStyle,
+};
+
+/// Draws a polyline through the given positions on the world map.
+///
+/// Unlike [`RoomVisual::poly`][crate::objects::RoomVisual::poly], points may
+/// be in different rooms.
+///
+/// See [http://docs.screeps.com/api/#Game-map-visual.poly]
+///
+/// [http://docs.screeps.com/api/#Game-map-visual.poly]: http://docs.screeps.com/api/#Game-map-visual.poly
+pub fn poly(points: &[Position], style: Option<PolyStyle>) {
+    let packed: Vec<i32> = points.iter().map(|p| p.packed_repr()).collect();
+    js! { @(no_return)
+        Game.map.visual.poly(@{packed}.map(pos_from_packed), @{style} || undefined);
+    }
+}
+
+/// Draws a route on the world map through each `(room, x, y)` step, connecting
+/// steps across room borders.
+///
+/// # Panics
+///
+/// Panics if any `x` or `y` is larger than 49.
+pub fn draw_path(steps: &[(RoomName, u8, u8)], style: Option<PolyStyle>) {
+    let points: Vec<Position> = steps
+        .iter()
+        .map(|&(room_name, x, y)| Position::new(x as u32, y as u32, room_name))
+        .collect();
+    poly(&points, style);
+}
+
+/// Converts an in-room tile to the coordinate system of the world map canvas
+/// drawn on by `Game.map.visual`.
+///
+/// Each room is a 50 unit cell, so the result is the tile's world coordinates
+/// (see [`Position::world_coords`]); integer values fall on tile centers, just
+/// like [`RoomVisual`][crate::objects::RoomVisual] coordinates.
+///
+/// # Panics
+///
+/// Panics if `x` or `y` is larger than 49.
+pub fn room_to_map_coords(room: RoomName, x: u8, y: u8) -> (f64, f64) {
+    let (world_x, world_y) = Position::new(x as u32, y as u32, room).world_coords();
+    (world_x as f64, world_y as f64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::room_to_map_coords;
+    use crate::local::RoomName;
+
+    #[test]
+    fn map_coords_offset_by_room() {
+        let room = |s: &str| RoomName::new(s).unwrap();
+        assert_eq!(room_to_map_coords(room("E0S0"), 5, 6), (5.0, 6.0));
+        assert_eq!(room_to_map_coords(room("E2S1"), 10, 0), (110.0, 50.0));
+        assert_eq!(room_to_map_coords(room("W0N0"), 49, 49), (-1.0, -1.0));
+        assert_eq!(room_to_map_coords(room("W1N1"), 0, 0), (-100.0, -100.0));
+    }
+}
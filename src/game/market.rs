@@ -99,6 +99,14 @@ pub struct Transaction {
 }
 js_deserializable!(Transaction);
 
+impl Transaction {
+    /// Price per unit this transaction was made at, if it filled a market
+    /// order.
+    pub fn price(&self) -> Option<f64> {
+        self.order.as_ref().map(|order| order.price)
+    }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
@@ -298,3 +306,35 @@ pub fn get_order(id: &str) -> Option<Order> {
     };
     order.try_into().ok()
 }
+
+#[cfg(test)]
+mod test {
+    use super::{OrderType, Transaction};
+    use crate::{constants::ResourceType, local::RoomName};
+
+    #[test]
+    fn transaction_deserializes() {
+        let json = r#"{
+            "transactionId": "56dec546a180ce641dd65960",
+            "time": 10390687,
+            "sender": {"username": "me"},
+            "recipient": null,
+            "resourceType": "U",
+            "amount": 100,
+            "from": "W1N1",
+            "to": "W2N2",
+            "description": null,
+            "order": {"id": "55c34a6b5be41a0a6e80c123", "type": "sell", "price": 2.95}
+        }"#;
+        let transaction: Transaction = serde_json::from_str(json).unwrap();
+        assert_eq!(transaction.resource_type, ResourceType::Utrium);
+        assert_eq!(transaction.from, RoomName::new("W1N1").unwrap());
+        assert_eq!(transaction.sender.as_ref().unwrap().username, "me");
+        assert!(transaction.recipient.is_none());
+        assert_eq!(
+            transaction.order.as_ref().unwrap().order_type,
+            OrderType::Sell
+        );
+        assert_eq!(transaction.price(), Some(2.95));
+    }
+}
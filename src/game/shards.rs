@@ -2,6 +2,8 @@
 //!
 //! [http://docs.screeps.com/api/#Game.shard]: http://docs.screeps.com/api/#Game.shard
 
+/// Name of the shard the code is running on, such as `"shard0"`.
+///
 /// See [http://docs.screeps.com/api/#Game.shard]
 ///
 /// [http://docs.screeps.com/api/#Game.shard]: http://docs.screeps.com/api/#Game.shard
@@ -9,6 +11,9 @@ pub fn name() -> String {
     js_unwrap!(Game.shard.name)
 }
 
+/// Type of the current shard, `Game.shard.type`. Currently always
+/// `"normal"`.
+///
 /// See [http://docs.screeps.com/api/#Game.shard]
 ///
 /// [http://docs.screeps.com/api/#Game.shard]: http://docs.screeps.com/api/#Game.shard
@@ -16,6 +21,8 @@ pub fn shard_type() -> String {
     js_unwrap!(Game.shard.type)
 }
 
+/// Whether the current shard is a public test realm shard.
+///
 /// See [http://docs.screeps.com/api/#Game.shard]
 ///
 /// [http://docs.screeps.com/api/#Game.shard]: http://docs.screeps.com/api/#Game.shard
@@ -0,0 +1,109 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Limits how often some work runs for each of a set of keys, such as
+/// [`ObjectId`]s or [`RoomName`]s.
+///
+/// Each key runs the first time it's checked, then at most once every
+/// `period` ticks after that. Keys first seen on different ticks stay out of
+/// step, which spreads heavy work across ticks.
+///
+/// This lives entirely in Rust, so it's lost on global resets, after which
+/// every key runs again on its next check.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::game::{self, Throttle};
+///
+/// let mut throttle = Throttle::new();
+/// for room in game::rooms::values() {
+///     if throttle.should_run(room.name(), 100) {
+///         // rescan the room
+///     }
+/// }
+/// ```
+///
+/// [`ObjectId`]: crate::local::ObjectId
+/// [`RoomName`]: crate::local::RoomName
+#[derive(Clone, Debug)]
+pub struct Throttle<K> {
+    last_run: HashMap<K, u32>,
+}
+
+impl<K> Default for Throttle<K> {
+    fn default() -> Self {
+        Throttle {
+            last_run: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq> Throttle<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the work for `key` should run this tick, recording that it ran
+    /// if so.
+    pub fn should_run(&mut self, key: K, period: u32) -> bool {
+        self.should_run_at(key, period, super::time())
+    }
+
+    /// Whether the work for `key` should run on tick `time`, recording that it
+    /// ran if so.
+    pub fn should_run_at(&mut self, key: K, period: u32, time: u32) -> bool {
+        match self.last_run.get(&key) {
+            Some(&last) if time.saturating_sub(last) < period => false,
+            _ => {
+                self.last_run.insert(key, time);
+                true
+            }
+        }
+    }
+
+    /// The tick `key` last ran on, if it's tracked.
+    pub fn last_run(&self, key: &K) -> Option<u32> {
+        self.last_run.get(key).copied()
+    }
+
+    /// Stops tracking `key`, so it runs on its next check.
+    pub fn forget(&mut self, key: &K) {
+        self.last_run.remove(key);
+    }
+
+    /// Stops tracking every key for which `keep` returns false, for instance
+    /// to drop destroyed objects.
+    pub fn retain(&mut self, mut keep: impl FnMut(&K) -> bool) {
+        self.last_run.retain(|key, _| keep(key));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Throttle;
+
+    #[test]
+    fn runs_once_per_period() {
+        let mut throttle = Throttle::new();
+        assert!(throttle.should_run_at("a", 10, 100));
+        assert!(!throttle.should_run_at("a", 10, 101));
+        assert!(!throttle.should_run_at("a", 10, 109));
+        assert!(throttle.should_run_at("a", 10, 110));
+        assert_eq!(throttle.last_run(&"a"), Some(110));
+    }
+
+    #[test]
+    fn keys_are_independent() {
+        let mut throttle = Throttle::new();
+        assert!(throttle.should_run_at("a", 10, 100));
+        assert!(throttle.should_run_at("b", 10, 105));
+        assert!(throttle.should_run_at("a", 10, 110));
+        assert!(!throttle.should_run_at("b", 10, 110));
+
+        throttle.forget(&"b");
+        assert!(throttle.should_run_at("b", 10, 111));
+
+        throttle.retain(|&k| k != "a");
+        assert_eq!(throttle.last_run(&"a"), None);
+    }
+}
@@ -0,0 +1,96 @@
+use std::ops::Range;
+
+/// Small, fast pseudo-random number generator, returned by [`rng`].
+///
+/// Uses the SplitMix64 algorithm: it isn't cryptographically secure, but is
+/// fully deterministic for a given seed, so results can be reproduced when
+/// debugging.
+///
+/// [`rng`]: crate::game::rng
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TickRng {
+    state: u64,
+}
+
+impl TickRng {
+    /// Creates a generator from an arbitrary seed.
+    pub fn from_seed(seed: u64) -> Self {
+        TickRng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns a number uniformly distributed in `0.0..1.0`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a number in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    pub fn gen_range(&mut self, range: Range<u32>) -> u32 {
+        assert!(range.start < range.end, "gen_range called with empty range");
+        let span = u64::from(range.end - range.start);
+        // multiply-shift rather than modulo to avoid bias toward low values
+        range.start + ((u64::from(self.next_u32()) * span) >> 32) as u32
+    }
+
+    /// Picks a random element of `items`, or `None` if it's empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            items.get(self.gen_range(0..items.len() as u32) as usize)
+        }
+    }
+
+    /// Shuffles `items` in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.gen_range(0..i as u32 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TickRng;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = TickRng::from_seed(42);
+        let mut b = TickRng::from_seed(42);
+        let mut c = TickRng::from_seed(43);
+        let seq: Vec<u64> = (0..4).map(|_| a.next_u64()).collect();
+        assert_eq!(seq, (0..4).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(seq, (0..4).map(|_| c.next_u64()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn gen_range_in_bounds() {
+        let mut rng = TickRng::from_seed(7);
+        for _ in 0..1000 {
+            let v = rng.gen_range(10..13);
+            assert!((10..13).contains(&v));
+            assert!((0.0..1.0).contains(&rng.next_f64()));
+        }
+
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+}
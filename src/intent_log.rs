@@ -0,0 +1,358 @@
+//! Recording the intents a bot issues, for later inspection.
+//!
+//! [`IntentLog`] wraps the common creep intent methods: each call is
+//! forwarded to the game as usual, and the intent and its [`ReturnCode`] are
+//! appended to a fixed-size ring buffer. Once the buffer is full the oldest
+//! records are dropped, so the log can be left enabled permanently.
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{Direction, ResourceType, ReturnCode},
+    game,
+    local::{ObjectId, Position, RawObjectId},
+    objects::{
+        Attackable, ConstructionSite, Creep, Harvestable, HasId, HasPosition, Resource,
+        SharedCreepProperties, StructureController, StructureProperties, Transferable,
+        Withdrawable,
+    },
+};
+
+/// An intent issued by a creep, mirroring the creep method which issued it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntentKind {
+    Move(Direction),
+    MoveTo(Position),
+    Transfer {
+        target: RawObjectId,
+        resource: ResourceType,
+        amount: Option<u32>,
+    },
+    Withdraw {
+        target: RawObjectId,
+        resource: ResourceType,
+        amount: Option<u32>,
+    },
+    Pickup(RawObjectId),
+    Harvest(RawObjectId),
+    Build(RawObjectId),
+    Repair(RawObjectId),
+    Dismantle(RawObjectId),
+    Attack(RawObjectId),
+    RangedAttack(RawObjectId),
+    Heal(RawObjectId),
+    UpgradeController(RawObjectId),
+}
+
+/// A single intent recorded by an [`IntentLog`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntentRecord {
+    pub tick: u32,
+    pub creep: ObjectId<Creep>,
+    pub kind: IntentKind,
+    pub result: ReturnCode,
+}
+
+/// Ring buffer of the most recent creep intents.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::{game, intent_log::IntentLog, Direction};
+///
+/// let mut log = IntentLog::new(1000);
+/// for creep in game::creeps::values() {
+///     log.move_direction(&creep, Direction::Top);
+/// }
+///
+/// for record in log.dump() {
+///     println!("{:?}", record);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntentLog {
+    capacity: usize,
+    records: VecDeque<IntentRecord>,
+}
+
+impl IntentLog {
+    /// Creates a log holding at most `capacity` records.
+    pub fn new(capacity: usize) -> Self {
+        IntentLog {
+            capacity,
+            records: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Appends a record, dropping the oldest one if the log is full.
+    pub fn record(
+        &mut self,
+        tick: u32,
+        creep: ObjectId<Creep>,
+        kind: IntentKind,
+        result: ReturnCode,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(IntentRecord {
+            tick,
+            creep,
+            kind,
+            result,
+        });
+    }
+
+    /// Copies out all records, oldest first.
+    pub fn dump(&self) -> Vec<IntentRecord> {
+        self.records.iter().cloned().collect()
+    }
+
+    /// Iterates over the records issued by one creep, oldest first.
+    pub fn for_creep(&self, creep: ObjectId<Creep>) -> impl Iterator<Item = &IntentRecord> {
+        self.records.iter().filter(move |r| r.creep == creep)
+    }
+
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    fn log(&mut self, creep: &Creep, kind: IntentKind, result: ReturnCode) -> ReturnCode {
+        self.record(game::time(), creep.id(), kind, result);
+        result
+    }
+
+    pub fn move_direction(&mut self, creep: &Creep, dir: Direction) -> ReturnCode {
+        let result = creep.move_direction(dir);
+        self.log(creep, IntentKind::Move(dir), result)
+    }
+
+    pub fn move_to<T>(&mut self, creep: &Creep, target: &T) -> ReturnCode
+    where
+        T: ?Sized + HasPosition,
+    {
+        let result = creep.move_to(target);
+        self.log(creep, IntentKind::MoveTo(target.pos()), result)
+    }
+
+    pub fn transfer_all<T>(&mut self, creep: &Creep, target: &T, ty: ResourceType) -> ReturnCode
+    where
+        T: ?Sized + Transferable + HasId,
+    {
+        let result = creep.transfer_all(target, ty);
+        let kind = IntentKind::Transfer {
+            target: target.untyped_id(),
+            resource: ty,
+            amount: None,
+        };
+        self.log(creep, kind, result)
+    }
+
+    pub fn transfer_amount<T>(
+        &mut self,
+        creep: &Creep,
+        target: &T,
+        ty: ResourceType,
+        amount: u32,
+    ) -> ReturnCode
+    where
+        T: ?Sized + Transferable + HasId,
+    {
+        let result = creep.transfer_amount(target, ty, amount);
+        let kind = IntentKind::Transfer {
+            target: target.untyped_id(),
+            resource: ty,
+            amount: Some(amount),
+        };
+        self.log(creep, kind, result)
+    }
+
+    pub fn withdraw_all<T>(&mut self, creep: &Creep, target: &T, ty: ResourceType) -> ReturnCode
+    where
+        T: ?Sized + Withdrawable + HasId,
+    {
+        let result = creep.withdraw_all(target, ty);
+        let kind = IntentKind::Withdraw {
+            target: target.untyped_id(),
+            resource: ty,
+            amount: None,
+        };
+        self.log(creep, kind, result)
+    }
+
+    pub fn withdraw_amount<T>(
+        &mut self,
+        creep: &Creep,
+        target: &T,
+        ty: ResourceType,
+        amount: u32,
+    ) -> ReturnCode
+    where
+        T: ?Sized + Withdrawable + HasId,
+    {
+        let result = creep.withdraw_amount(target, ty, amount);
+        let kind = IntentKind::Withdraw {
+            target: target.untyped_id(),
+            resource: ty,
+            amount: Some(amount),
+        };
+        self.log(creep, kind, result)
+    }
+
+    pub fn pickup(&mut self, creep: &Creep, target: &Resource) -> ReturnCode {
+        let result = creep.pickup(target);
+        self.log(creep, IntentKind::Pickup(target.untyped_id()), result)
+    }
+
+    pub fn harvest<T>(&mut self, creep: &Creep, target: &T) -> ReturnCode
+    where
+        T: ?Sized + Harvestable + HasId,
+    {
+        let result = creep.harvest(target);
+        self.log(creep, IntentKind::Harvest(target.untyped_id()), result)
+    }
+
+    pub fn build(&mut self, creep: &Creep, target: &ConstructionSite) -> ReturnCode {
+        let result = creep.build(target);
+        self.log(creep, IntentKind::Build(target.untyped_id()), result)
+    }
+
+    pub fn repair<T>(&mut self, creep: &Creep, target: &T) -> ReturnCode
+    where
+        T: ?Sized + StructureProperties + HasId,
+    {
+        let result = creep.repair(target);
+        self.log(creep, IntentKind::Repair(target.untyped_id()), result)
+    }
+
+    pub fn dismantle<T>(&mut self, creep: &Creep, target: &T) -> ReturnCode
+    where
+        T: ?Sized + StructureProperties + HasId,
+    {
+        let result = creep.dismantle(target);
+        self.log(creep, IntentKind::Dismantle(target.untyped_id()), result)
+    }
+
+    pub fn attack<T>(&mut self, creep: &Creep, target: &T) -> ReturnCode
+    where
+        T: ?Sized + Attackable + HasId,
+    {
+        let result = creep.attack(target);
+        self.log(creep, IntentKind::Attack(target.untyped_id()), result)
+    }
+
+    pub fn ranged_attack<T>(&mut self, creep: &Creep, target: &T) -> ReturnCode
+    where
+        T: ?Sized + Attackable + HasId,
+    {
+        let result = creep.ranged_attack(target);
+        self.log(creep, IntentKind::RangedAttack(target.untyped_id()), result)
+    }
+
+    pub fn heal<T>(&mut self, creep: &Creep, target: &T) -> ReturnCode
+    where
+        T: ?Sized + SharedCreepProperties + HasId,
+    {
+        let result = creep.heal(target);
+        self.log(creep, IntentKind::Heal(target.untyped_id()), result)
+    }
+
+    pub fn upgrade_controller(
+        &mut self,
+        creep: &Creep,
+        target: &StructureController,
+    ) -> ReturnCode {
+        let result = creep.upgrade_controller(target);
+        self.log(
+            creep,
+            IntentKind::UpgradeController(target.untyped_id()),
+            result,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{IntentKind, IntentLog};
+    use crate::{
+        constants::{Direction, ReturnCode},
+        local::ObjectId,
+        objects::Creep,
+    };
+
+    fn creep_id(n: u128) -> ObjectId<Creep> {
+        format!("{:x}", n).parse().unwrap()
+    }
+
+    #[test]
+    fn ring_buffer_drops_oldest() {
+        let mut log = IntentLog::new(2);
+        for tick in 0..3 {
+            log.record(
+                tick,
+                creep_id(1),
+                IntentKind::Move(Direction::Top),
+                ReturnCode::Ok,
+            );
+        }
+        let ticks: Vec<u32> = log.dump().iter().map(|r| r.tick).collect();
+        assert_eq!(ticks, vec![1, 2]);
+
+        let mut empty = IntentLog::new(0);
+        empty.record(
+            0,
+            creep_id(1),
+            IntentKind::Move(Direction::Top),
+            ReturnCode::Ok,
+        );
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn per_creep_history() {
+        let mut log = IntentLog::new(10);
+        log.record(
+            1,
+            creep_id(1),
+            IntentKind::Move(Direction::Top),
+            ReturnCode::Ok,
+        );
+        log.record(
+            1,
+            creep_id(2),
+            IntentKind::Move(Direction::Left),
+            ReturnCode::Tired,
+        );
+        log.record(
+            2,
+            creep_id(1),
+            IntentKind::Move(Direction::Right),
+            ReturnCode::Ok,
+        );
+
+        let kinds: Vec<&IntentKind> = log.for_creep(creep_id(1)).map(|r| &r.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &IntentKind::Move(Direction::Top),
+                &IntentKind::Move(Direction::Right)
+            ]
+        );
+    }
+}
@@ -25,8 +25,10 @@ extern crate stdweb;
 #[macro_use]
 pub mod macros;
 
+pub mod assignment;
 pub mod constants;
 pub mod game;
+pub mod intent_log;
 pub mod inter_shard_memory;
 pub mod js_collections;
 pub mod local;
@@ -34,6 +36,7 @@ pub mod memory;
 pub mod objects;
 pub mod pathfinder;
 pub mod raw_memory;
+pub mod traffic;
 pub mod traits;
 
 pub use stdweb::private::ConversionError;
@@ -67,6 +70,6 @@ pub type RoomPosition = Position;
 pub mod prelude {
     pub use crate::objects::{
         CanDecay, HasCooldown, HasId, HasPosition, HasStore, OwnedStructureProperties,
-        RoomObjectProperties, SharedCreepProperties, StructureProperties,
+        ResourceSink, RoomObjectProperties, SharedCreepProperties, StructureProperties,
     };
 }
@@ -2,6 +2,7 @@
 use std::ops::Range;
 
 mod object_id;
+mod position_grid;
 mod room_name;
 mod room_position;
 
@@ -18,4 +19,4 @@ const HALF_WORLD_SIZE: i32 = 128;
 /// Valid room name coordinates.
 const VALID_ROOM_NAME_COORDINATES: Range<i32> = -HALF_WORLD_SIZE..HALF_WORLD_SIZE;
 
-pub use self::{object_id::*, room_name::*, room_position::*};
+pub use self::{object_id::*, position_grid::*, room_name::*, room_position::*};
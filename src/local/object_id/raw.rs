@@ -309,6 +309,27 @@ mod test {
         }
     }
 
+    #[test]
+    fn ordering_matches_full_length_string_ordering() {
+        let mut ids = vec![
+            "5f1a2b3c4d5e6f7081920a1b",
+            "5f1a2b3c4d5e6f7081920a1a",
+            "0000000000000000000000ff",
+            "ffffffffffffffffffffffff",
+            "5f1a2b3c4d5e6f7081920b00",
+        ];
+        let mut parsed: Vec<RawObjectId> = ids.iter().map(|id| id.parse().unwrap()).collect();
+
+        ids.sort();
+        parsed.sort();
+
+        let reformatted: Vec<String> = parsed
+            .iter()
+            .map(|id| format!("{:0>24}", id.to_string()))
+            .collect();
+        assert_eq!(reformatted, ids);
+    }
+
     #[test]
     fn large_values_do_not_parse() {
         let large_ids = &[
@@ -0,0 +1,141 @@
+//! Spatial index of positioned items.
+use std::collections::HashMap;
+
+use crate::{local::Position, objects::HasPosition};
+
+/// Items bucketed by their exact position, for repeated "what's within range
+/// of here" queries.
+///
+/// Build this once per tick from items which don't move during the tick, then
+/// query it instead of checking every pair of items. Queries only cover
+/// `(2 * range + 1)^2` tiles, and never match items in a different room from
+/// the queried position.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{local::PositionGrid, Position};
+///
+/// let room = "E1N1".parse().unwrap();
+/// let grid: PositionGrid<Position> = vec![
+///     Position::new(10, 10, room),
+///     Position::new(12, 13, room),
+///     Position::new(30, 30, room),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert_eq!(grid.query_range(Position::new(11, 11, room), 2).count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PositionGrid<T> {
+    buckets: HashMap<Position, Vec<T>>,
+    len: usize,
+}
+
+impl<T> Default for PositionGrid<T> {
+    fn default() -> Self {
+        PositionGrid {
+            buckets: HashMap::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T: HasPosition> PositionGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an item at its current position.
+    pub fn insert(&mut self, item: T) {
+        self.buckets.entry(item.pos()).or_default().push(item);
+        self.len += 1;
+    }
+
+    /// Number of items in the grid.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Items at exactly `pos`.
+    pub fn at(&self, pos: Position) -> &[T] {
+        self.buckets.get(&pos).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Items in the same room as `pos` and within `range` tiles of it, by the
+    /// same chebyshev distance as [`Position::get_range_to`].
+    pub fn query_range(&self, pos: Position, range: u32) -> impl Iterator<Item = &T> {
+        let room = pos.room_name();
+        let (x, y) = (pos.x(), pos.y());
+        let xs = x.saturating_sub(range)..=x.saturating_add(range).min(49);
+        let ys = y.saturating_sub(range)..=y.saturating_add(range).min(49);
+
+        xs.flat_map(move |x| ys.clone().map(move |y| Position::new(x, y, room)))
+            .flat_map(move |p| self.at(p))
+    }
+}
+
+impl<T: HasPosition> Extend<T> for PositionGrid<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T: HasPosition> std::iter::FromIterator<T> for PositionGrid<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut grid = PositionGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PositionGrid;
+    use crate::local::Position;
+
+    #[test]
+    fn query_range_stays_in_room() {
+        let a = "E1N1".parse().unwrap();
+        let b = "E2N1".parse().unwrap();
+        let grid: PositionGrid<Position> = vec![
+            Position::new(0, 0, a),
+            Position::new(49, 0, a),
+            Position::new(0, 0, b),
+            Position::new(1, 1, b),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(grid.len(), 4);
+        let near_edge: Vec<Position> = grid
+            .query_range(Position::new(48, 1, a), 3)
+            .copied()
+            .collect();
+        assert_eq!(near_edge, vec![Position::new(49, 0, a)]);
+        assert_eq!(grid.query_range(Position::new(0, 0, b), 1).count(), 2);
+        assert_eq!(grid.query_range(Position::new(25, 25, a), 10).count(), 0);
+    }
+
+    #[test]
+    fn query_range_unbounded() {
+        let room = "E1N1".parse().unwrap();
+        let grid: PositionGrid<Position> = vec![
+            Position::new(0, 0, room),
+            Position::new(49, 49, room),
+            Position::new(0, 0, "E2N1".parse().unwrap()),
+        ]
+        .into_iter()
+        .collect();
+
+        let all = grid.query_range(Position::new(25, 25, room), u32::MAX);
+        assert_eq!(all.count(), 2);
+    }
+}
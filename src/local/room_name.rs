@@ -153,6 +153,35 @@ impl RoomName {
     }
 }
 
+/// Iterates over every room name in the rectangle with corners `from` and `to`,
+/// inclusive.
+///
+/// The corners may be given in any order. Rooms are yielded in the same order
+/// as [`RoomName`]'s [`Ord`] implementation: row by row from north to south,
+/// and west to east within each row. Ranges spanning the `W0`/`E0` or `N0`/`S0`
+/// seams are handled, as iteration is done on the underlying coordinates.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{local::room_range, RoomName};
+///
+/// let rooms: Vec<RoomName> = room_range("W0N0".parse().unwrap(), "E0S0".parse().unwrap())
+///     .collect();
+/// assert_eq!(rooms, ["W0N0", "E0N0", "W0S0", "E0S0"]);
+/// ```
+pub fn room_range(from: RoomName, to: RoomName) -> impl Iterator<Item = RoomName> {
+    let (x1, x2) = (from.x_coord(), to.x_coord());
+    let (y1, y2) = (from.y_coord(), to.y_coord());
+    let xs = x1.min(x2)..=x1.max(x2);
+
+    (y1.min(y2)..=y1.max(y2)).flat_map(move |y| {
+        xs.clone().map(move |x| {
+            RoomName::from_coords(x, y).expect("expected coords between two room names to be valid")
+        })
+    })
+}
+
 impl ops::Add<(i32, i32)> for RoomName {
     type Output = Self;
 
@@ -463,4 +492,33 @@ mod test {
             assert_eq!(&room_name.to_string(), RoomName::new(room_name).unwrap());
         }
     }
+
+    #[test]
+    fn test_room_range_across_seams() {
+        use super::{room_range, RoomName};
+        let from: RoomName = "E1S1".parse().unwrap();
+        let to: RoomName = "W1N1".parse().unwrap();
+        let rooms: Vec<RoomName> = room_range(from, to).collect();
+        assert_eq!(rooms.len(), 16);
+        assert_eq!(rooms[0], "W1N1");
+        assert_eq!(rooms[3], "E1N1");
+        assert_eq!(rooms[15], "E1S1");
+        assert!(rooms.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_serialize_as_map_key() {
+        use super::RoomName;
+        use std::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert(RoomName::new("W5N8").unwrap(), 1);
+        map.insert(RoomName::new("E0S0").unwrap(), 2);
+
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"W5N8":1,"E0S0":2}"#);
+
+        let back: BTreeMap<RoomName, u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, map);
+    }
 }
@@ -2,6 +2,8 @@
 //! proper.
 use std::ops::{Add, Sub};
 
+use crate::constants::ExitDirection;
+
 use super::Position;
 
 impl Position {
@@ -39,6 +41,41 @@ impl Position {
     pub fn offset(&mut self, x: i32, y: i32) {
         *self = *self + (x, y);
     }
+
+    /// Returns the tile on the given exit edge of this position's room which
+    /// is nearest to this position.
+    ///
+    /// The coordinate along the edge is preserved, and the other coordinate is
+    /// clamped to the edge: `x = 0` for [`ExitDirection::Left`], `x = 49` for
+    /// [`ExitDirection::Right`], `y = 0` for [`ExitDirection::Top`] and `y =
+    /// 49` for [`ExitDirection::Bottom`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use screeps::{ExitDirection, Position};
+    ///
+    /// let e5n5 = "E5N5".parse().unwrap();
+    /// let pos = Position::new(20, 31, e5n5);
+    ///
+    /// assert_eq!(
+    ///     pos.exit_tile_toward(ExitDirection::Left),
+    ///     Position::new(0, 31, e5n5)
+    /// );
+    /// assert_eq!(
+    ///     pos.exit_tile_toward(ExitDirection::Bottom),
+    ///     Position::new(20, 49, e5n5)
+    /// );
+    /// ```
+    #[inline]
+    pub fn exit_tile_toward(self, exit: ExitDirection) -> Position {
+        match exit {
+            ExitDirection::Top => self.with_y(0),
+            ExitDirection::Right => self.with_x(49),
+            ExitDirection::Bottom => self.with_y(49),
+            ExitDirection::Left => self.with_x(0),
+        }
+    }
 }
 
 impl Add<(i32, i32)> for Position {
@@ -54,6 +54,17 @@ impl Position {
         )
     }
 
+    /// Finds all objects of the given type within `range` tiles of this
+    /// position.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use screeps::{find, game, prelude::*};
+    ///
+    /// let tower_pos = game::creeps::values()[0].pos();
+    /// let nearby_hostiles = tower_pos.find_in_range(find::HOSTILE_CREEPS, 5);
+    /// ```
     pub fn find_in_range<T>(self, ty: T, range: u32) -> Vec<T::Item>
     where
         T: FindConstant,
@@ -255,12 +255,14 @@ macro_rules! creep_simple_generic_action {
     (
         impl $struct_name:ident {
             $(
+                $(#[$attr:meta])*
                 $vis:vis fn $method:ident($trait:ident) = $js_name:ident ();
             )+
         }
     ) => (
         impl $struct_name {
             $(
+                $(#[$attr])*
                 $vis fn $method<T>(&self, target: &T) -> ReturnCode
                 where
                     T: ?Sized + $trait,
@@ -296,12 +298,14 @@ macro_rules! creep_simple_concrete_action {
     (
         impl $struct_name:ident {
             $(
+                $(#[$attr:meta])*
                 $vis:vis fn $method:ident($type:ty) = $js_name:ident ();
             )+
         }
     ) => (
         impl $struct_name {
             $(
+                $(#[$attr])*
                 $vis fn $method(&self, target: &$type) -> ReturnCode {
                     js_unwrap!(@{self.as_ref()}.$js_name(@{target.as_ref()}))
                 }
@@ -782,3 +786,44 @@ macro_rules! mem_set {
         compile_error!(concat!("Unexpected usage of mem_set! usage: ", stringify!($($not_valid)*)))
     }
 }
+
+/// Builds a creep body as a `Vec<Part>` from part names and literal counts.
+///
+/// The total size is checked against [`MAX_CREEP_SIZE`] at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use screeps::{body, Part};
+///
+/// let body = body![Work 2, Carry 1, Move 2];
+/// assert_eq!(
+///     body,
+///     vec![Part::Work, Part::Work, Part::Carry, Part::Move, Part::Move]
+/// );
+/// ```
+///
+/// Bodies with more than [`MAX_CREEP_SIZE`] parts fail to compile:
+///
+/// ```compile_fail
+/// use screeps::body;
+///
+/// let body = body![Work 20, Carry 20, Move 20];
+/// ```
+///
+/// [`MAX_CREEP_SIZE`]: crate::constants::MAX_CREEP_SIZE
+#[macro_export]
+macro_rules! body {
+    ($($part:ident $count:literal),* $(,)?) => {{
+        const _: () = assert!(
+            0usize $(+ $count)* <= $crate::constants::MAX_CREEP_SIZE as usize,
+            "creep body has more than MAX_CREEP_SIZE parts"
+        );
+        #[allow(unused_mut)]
+        let mut body = ::std::vec::Vec::with_capacity(0usize $(+ $count)*);
+        $(
+            body.extend(::std::iter::repeat($crate::constants::Part::$part).take($count));
+        )*
+        body
+    }};
+}
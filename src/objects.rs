@@ -30,11 +30,17 @@ mod structure;
 pub use self::{
     creep_shared::{MoveToOptions, SharedCreepProperties},
     impls::{
-        AttackEvent, AttackType, Bodypart, BuildEvent, CircleStyle, Effect, Event, EventType,
-        ExitEvent, FindOptions, FontStyle, HarvestEvent, HealEvent, HealType, LineDrawStyle,
-        LineStyle, LookResult, ObjectDestroyedEvent, Path, PolyStyle, PortalDestination,
-        PositionedLookResult, RectStyle, RepairEvent, Reservation, ReserveControllerEvent,
-        RoomVisual, Sign, SpawnOptions, Step, TextAlign, TextStyle, UpgradeControllerEvent, Visual,
+        balance_links, best_pickup, can_run_reaction, diff_look_grids, effective_damage,
+        fatigue_per_step, harvest_yield, net_fatigue_per_step, open_positions, pack_path,
+        power_bank_hitback_damage, unboosted_parts, unpack_path, AttackEvent, AttackType,
+        BodyScaler, Bodypart, BuildEvent, CircleStyle, Effect, Event, EventType, ExitEvent,
+        FindOptions, FindRoomOptions, FontStyle, HarvestEvent, HealEvent, HealType, InferredRole,
+        LabCooldownTracker, LineDrawStyle, LineStyle, LookChange, LookDelta, LookGrid, LookResult,
+        MoveSpeed, MoveState, ObjectDestroyedEvent, ObserverSchedule, Path, PickupTarget,
+        PolyStyle, PortalDestination, PositionedLookResult, RectStyle, RepairEvent, Reservation,
+        ReserveControllerEvent, RoomSnapshot, RoomVisual, SendError, Sign, SpawnError,
+        SpawnOptions, Step, StructureIndex, TextAlign, TextStyle, ThreatSummary,
+        UpgradeControllerEvent, Visual,
     },
     structure::Structure,
 };
@@ -135,6 +141,23 @@ impl HasPosition for Position {
     }
 }
 
+/// Finds the item in `items` closest to `origin` by linear range.
+///
+/// Range is computed with [`Position::get_range_to`], so items in other rooms
+/// are compared by their real distance across room borders rather than being
+/// treated as infinitely far away. Ties are broken by the first item in the
+/// slice.
+///
+/// To compare by path length instead, see
+/// [`pathfinder::closest_by_path`][crate::pathfinder::closest_by_path].
+pub fn closest<'a, T>(origin: &impl HasPosition, items: &'a [T]) -> Option<&'a T>
+where
+    T: HasPosition,
+{
+    let origin = origin.pos();
+    items.iter().min_by_key(|item| origin.get_range_to(*item))
+}
+
 /// All `RoomObject`s have positions.
 impl<T> HasPosition for T
 where
@@ -341,7 +364,17 @@ pub unsafe trait OwnedStructureProperties: StructureProperties {
 /// The `store` property must be a dict from string resource types to integers,
 /// and have the `getCapacity`, `getFreeCapacity`, and `getUsedCapacity`
 /// functions
+///
+/// # Restricted stores
+///
+/// Some stores can only hold specific resources: extensions, spawns and towers
+/// only hold energy, and labs hold energy plus a single mineral type at a time.
+/// For these, [`HasStore::store_capacity`] and [`HasStore::store_free_capacity`]
+/// return `0` for any resource which [`HasStore::store_can_hold`] rejects, and
+/// `store_capacity(None)` / `store_free_capacity(None)` also return `0`, as
+/// these stores have no general capacity shared between resources.
 pub unsafe trait HasStore: RoomObjectProperties {
+    /// Total amount of all resources in this store.
     fn store_total(&self) -> u32 {
         js_unwrap!(_.sum(@{self.as_ref()}.store))
     }
@@ -350,30 +383,47 @@ pub unsafe trait HasStore: RoomObjectProperties {
         js_unwrap!(Object.keys(@{self.as_ref()}.store).map(__resource_type_str_to_num))
     }
 
+    /// Every resource in this store paired with its amount, skipping resources
+    /// with an amount of `0`.
+    fn store_contents(&self) -> Vec<(ResourceType, u32)> {
+        let store = self.as_ref();
+        let types: Vec<ResourceType> =
+            js_unwrap!(Object.keys(@{store}.store).map(__resource_type_str_to_num));
+        // same property order as Object.keys
+        let amounts: Vec<u32> = js_unwrap!(Object.values(@{store}.store));
+        types
+            .into_iter()
+            .zip(amounts)
+            .filter(|&(_, amount)| amount > 0)
+            .collect()
+    }
+
+    /// Amount of the given resource in this store (in JS: `store[ty] || 0`).
     fn store_of(&self, ty: ResourceType) -> u32 {
         js_unwrap!(@{self.as_ref()}.store[__resource_type_num_to_str(@{ty as u32})] || 0)
     }
 
+    /// Amount of energy in this store (in JS: `store.energy`).
+    ///
+    /// For creeps, this replaces the removed `creep.carry.energy`.
     fn energy(&self) -> u32 {
         js_unwrap!(@{self.as_ref()}.store[RESOURCE_ENERGY])
     }
 
+    /// Whether this store is currently able to hold the given resource.
+    ///
+    /// For general stores, this is always `true`. For restricted stores, the
+    /// game reports a `null` capacity for resources they can't hold.
+    fn store_can_hold(&self, ty: ResourceType) -> bool {
+        js_unwrap!(@{self.as_ref()}.store.getCapacity(__resource_type_num_to_str(@{ty as u32})) != null)
+    }
+
     fn store_capacity(&self, resource: Option<ResourceType>) -> u32 {
-        match resource {
-            Some(ty) => {
-                js_unwrap!(@{self.as_ref()}.store.getCapacity(__resource_type_num_to_str(@{ty as u32})) || 0)
-            }
-            None => js_unwrap!(@{self.as_ref()}.store.getCapacity() || 0),
-        }
+        get_store_capacity(self.as_ref(), resource)
     }
 
     fn store_free_capacity(&self, resource: Option<ResourceType>) -> i32 {
-        match resource {
-            Some(ty) => {
-                js_unwrap!(@{self.as_ref()}.store.getFreeCapacity(__resource_type_num_to_str(@{ty as u32})) || 0)
-            }
-            None => js_unwrap!(@{self.as_ref()}.store.getFreeCapacity() || 0),
-        }
+        get_store_free_capacity(self.as_ref(), resource)
     }
 
     fn store_used_capacity(&self, resource: Option<ResourceType>) -> u32 {
@@ -386,6 +436,62 @@ pub unsafe trait HasStore: RoomObjectProperties {
     }
 }
 
+/// `store.getCapacity(resource)` for the object referenced by `obj`, treating a
+/// `null` capacity (a resource the store can't hold) as `0`.
+fn get_store_capacity(obj: &Reference, resource: Option<ResourceType>) -> u32 {
+    match resource {
+        Some(ty) => js_unwrap!(
+            @{obj}.store.getCapacity(__resource_type_num_to_str(@{ty as u32})) || 0
+        ),
+        None => js_unwrap!(@{obj}.store.getCapacity() || 0),
+    }
+}
+
+/// `store.getFreeCapacity(resource)` for the object referenced by `obj`, or `0`
+/// for a resource the store can't hold.
+fn get_store_free_capacity(obj: &Reference, resource: Option<ResourceType>) -> i32 {
+    match resource {
+        Some(ty) => js_unwrap!(
+            (@{obj}.store.getCapacity(__resource_type_num_to_str(@{ty as u32})) == null)
+                ? 0
+                : (@{obj}.store.getFreeCapacity(__resource_type_num_to_str(@{ty as u32})) || 0)
+        ),
+        None => js_unwrap!(@{obj}.store.getFreeCapacity() || 0),
+    }
+}
+
+/// Whether `target` could currently accept any amount of `resource`.
+///
+/// This is `false` both when the store can't hold the resource at all (see
+/// [`HasStore::store_can_hold`]), such as power into an extension, and when
+/// it has no free capacity left for it.
+pub fn can_accept(target: &impl HasStore, resource: ResourceType) -> bool {
+    target.store_can_hold(resource) && target.store_free_capacity(Some(resource)) > 0
+}
+
+/// Trait for structures which act as destinations for hauled resources.
+///
+/// This unifies storage-like structures and structures consuming resources,
+/// applying each structure's rules about which resources it can hold.
+///
+/// Unlike most traits here, this has no contract of its own beyond those of
+/// [`HasStore`] and [`Transferable`], so it isn't `unsafe` to implement.
+pub trait ResourceSink: HasStore + Transferable {
+    /// Whether this structure can hold the given resource at all.
+    fn accepts(&self, ty: ResourceType) -> bool {
+        self.store_can_hold(ty)
+    }
+
+    /// Free capacity for the given resource, or `0` if it isn't accepted.
+    fn free_for(&self, ty: ResourceType) -> u32 {
+        if self.accepts(ty) {
+            self.store_free_capacity(Some(ty)).max(0) as u32
+        } else {
+            0
+        }
+    }
+}
+
 /// Used to specify which structures can use their stored energy for spawning
 /// creeps.
 ///
@@ -622,12 +728,45 @@ unsafe impl OwnedStructureProperties for StructureTower {}
 // NOTE: keep impls for Structure* in sync with accessor methods in
 // src/objects/structure.rs
 
+/// Creeps have a general store, shared between all resource types, sized by
+/// their active `CARRY` parts.
+///
+/// This replaces the deprecated `Creep.carry` and `Creep.carryCapacity`
+/// properties, which are not bound: use [`HasStore::store_of`] (or
+/// [`HasStore::energy`]) in place of `carry[resource]`, and
+/// `store_capacity(None)` in place of `carryCapacity`.
+/// `store_free_capacity(None)` gives the remaining space for any resource.
 unsafe impl HasStore for Creep {}
 unsafe impl HasStore for Ruin {}
 unsafe impl HasStore for StructureContainer {}
 unsafe impl HasStore for StructureExtension {}
 unsafe impl HasStore for StructureFactory {}
-unsafe impl HasStore for StructureLab {}
+unsafe impl HasStore for StructureLab {
+    /// Labs hold energy and a single mineral type at a time: while a mineral is
+    /// loaded, any other mineral can't be held.
+    fn store_can_hold(&self, ty: ResourceType) -> bool {
+        match (ty, self.mineral_type()) {
+            (ResourceType::Energy, _) | (_, None) => js_unwrap!(
+                @{self.as_ref()}.store.getCapacity(__resource_type_num_to_str(@{ty as u32})) != null
+            ),
+            (ty, Some(mineral)) => ty == mineral,
+        }
+    }
+
+    fn store_capacity(&self, resource: Option<ResourceType>) -> u32 {
+        match resource {
+            Some(ty) if !self.store_can_hold(ty) => 0,
+            _ => get_store_capacity(self.as_ref(), resource),
+        }
+    }
+
+    fn store_free_capacity(&self, resource: Option<ResourceType>) -> i32 {
+        match resource {
+            Some(ty) if !self.store_can_hold(ty) => 0,
+            _ => get_store_free_capacity(self.as_ref(), resource),
+        }
+    }
+}
 unsafe impl HasStore for StructureLink {}
 unsafe impl HasStore for StructureNuker {}
 unsafe impl HasStore for StructurePowerSpawn {}
@@ -641,6 +780,19 @@ unsafe impl HasStore for PowerCreep {}
 // NOTE: keep impls for Structure* in sync with accessor methods in
 // src/objects/structure.rs
 
+impl ResourceSink for StructureContainer {}
+impl ResourceSink for StructureExtension {}
+impl ResourceSink for StructureLab {}
+impl ResourceSink for StructureNuker {}
+impl ResourceSink for StructurePowerSpawn {}
+impl ResourceSink for StructureSpawn {}
+impl ResourceSink for StructureStorage {}
+impl ResourceSink for StructureTerminal {}
+impl ResourceSink for StructureTower {}
+
+// NOTE: keep impls for Structure* in sync with accessor methods in
+// src/objects/structure.rs
+
 unsafe impl HasEnergyForSpawn for StructureExtension {}
 unsafe impl HasEnergyForSpawn for StructureSpawn {}
 
@@ -80,6 +80,7 @@ pub unsafe trait SharedCreepProperties: RoomObjectProperties {
                 FindOptions {
                     ignore_creeps,
                     ignore_destructible_structures,
+                    ignore_roads,
                     cost_callback,
                     max_ops,
                     heuristic_weight,
@@ -116,27 +117,34 @@ pub unsafe trait SharedCreepProperties: RoomObjectProperties {
         let callback_lifetime_erased: &'static mut dyn FnMut(RoomName, Reference) -> Value =
             unsafe { mem::transmute(callback_type_erased) };
 
+        // Pathfinding options are built separately to keep the number of
+        // interpolated values in a single `js!` call manageable.
+        let find_options: Reference = js_unwrap!({
+            ignoreCreeps: @{ignore_creeps},
+            ignoreDestructibleStructures: @{ignore_destructible_structures},
+            ignoreRoads: @{ignore_roads},
+            maxOps: @{max_ops},
+            heuristicWeight: @{heuristic_weight},
+            serialize: @{serialize},
+            maxRooms: @{max_rooms},
+            range: @{range},
+            plainCost: @{plain_cost},
+            swampCost: @{swamp_cost}
+        });
+
         let rp = target.pos();
         js!(
             let cb = @{callback_lifetime_erased};
+            let options = Object.assign(@{find_options}, {
+                reusePath: @{reuse_path},
+                serializeMemory: @{serialize_memory},
+                noPathFinding: @{no_path_finding},
+                visualizePathStyle: @{visualize_path_style},
+                costCallback: cb
+            });
             let res = @{ self.as_ref() }.moveTo(
                 pos_from_packed(@{rp.packed_repr()}),
-                {
-                    reusePath: @{reuse_path},
-                    serializeMemory: @{serialize_memory},
-                    noPathFinding: @{no_path_finding},
-                    visualizePathStyle: @{visualize_path_style},
-                    ignoreCreeps: @{ignore_creeps},
-                    ignoreDestructibleStructures: @{ignore_destructible_structures},
-                    costCallback: cb,
-                    maxOps: @{max_ops},
-                    heuristicWeight: @{heuristic_weight},
-                    serialize: @{serialize},
-                    maxRooms: @{max_rooms},
-                    range: @{range},
-                    plainCost: @{plain_cost},
-                    swampCost: @{swamp_cost}
-                }
+                options
             );
             cb.drop();
             return res;
@@ -247,6 +255,25 @@ pub unsafe trait SharedCreepProperties: RoomObjectProperties {
 unsafe impl SharedCreepProperties for Creep {}
 unsafe impl SharedCreepProperties for PowerCreep {}
 
+/// Options for [`SharedCreepProperties::move_to_with_options`] and
+/// [`SharedCreepProperties::move_to_xy_with_options`], extending
+/// [`FindOptions`] with `moveTo`'s path caching and visualization settings.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::{game, prelude::*, MoveToOptions};
+///
+/// let creep = game::creeps::values().pop().unwrap();
+/// let target = game::flags::values().pop().unwrap();
+/// creep.move_to_with_options(
+///     &target,
+///     MoveToOptions::new()
+///         .range(1)
+///         .reuse_path(20)
+///         .ignore_creeps(true),
+/// );
+/// ```
 pub struct MoveToOptions<'a, F>
 where
     F: FnMut(RoomName, CostMatrix<'a>) -> SingleRoomCostResult<'a>,
@@ -275,7 +302,7 @@ impl Default
 }
 
 impl MoveToOptions<'static, fn(RoomName, CostMatrix<'static>) -> SingleRoomCostResult<'static>> {
-    /// Creates default SearchOptions
+    /// Creates default MoveToOptions
     pub fn new() -> Self {
         Self::default()
     }
@@ -323,6 +350,35 @@ where
         self
     }
 
+    /// Sets whether the algorithm ignores roads, treating them like the
+    /// terrain beneath. Default: False.
+    pub fn ignore_roads(mut self, ignore: bool) -> Self {
+        self.find_options.ignore_roads = ignore;
+        self
+    }
+
+    /// Sets terrain costs for whether this creep gains anything from roads.
+    ///
+    /// With `true`, for creeps with enough `MOVE` parts to move at full speed
+    /// on plains, roads are ignored and plains and swamps cost `1` and `5`.
+    ///
+    /// With `false`, for creeps which only move at full speed on roads, plains
+    /// and swamps cost `2` and `10`, so that roads (cost `1`) are preferred.
+    ///
+    /// This overrides earlier calls to [`MoveToOptions::ignore_roads`],
+    /// [`MoveToOptions::plain_cost`] and [`MoveToOptions::swamp_cost`].
+    pub fn off_road(mut self, off_road: bool) -> Self {
+        self.find_options.ignore_roads = off_road;
+        if off_road {
+            self.find_options.plain_cost = 1;
+            self.find_options.swamp_cost = 5;
+        } else {
+            self.find_options.plain_cost = 2;
+            self.find_options.swamp_cost = 10;
+        }
+        self
+    }
+
     /// Sets cost callback - default `|_, _| {}`.
     pub fn cost_callback<'b, F2>(self, cost_callback: F2) -> MoveToOptions<'b, F2>
     where
@@ -363,6 +419,7 @@ where
         self
     }
 
+    /// Sets the range from the target at which to stop - default `0`.
     pub fn range(mut self, k: u32) -> Self {
         self.find_options.range = k;
         self
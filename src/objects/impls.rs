@@ -2,17 +2,20 @@ mod construction_site;
 mod creep;
 mod deposit;
 mod flag;
+mod look_grid;
 mod mineral;
 mod nuke;
 mod power_creep;
 mod resource;
 mod room;
+mod room_snapshot;
 mod room_terrain;
 mod room_visual;
 mod ruin;
 mod source;
 mod structure_controller;
 mod structure_factory;
+mod structure_index;
 mod structure_invader_core;
 mod structure_keeper_lair;
 mod structure_lab;
@@ -29,17 +32,30 @@ mod structure_tower;
 mod tombstone;
 
 pub use self::{
-    creep::Bodypart,
+    creep::{
+        fatigue_per_step, net_fatigue_per_step, unboosted_parts, Bodypart, InferredRole, MoveState,
+    },
+    look_grid::{diff_look_grids, LookChange, LookDelta, LookGrid},
+    resource::{best_pickup, PickupTarget},
     room::{
-        AttackEvent, AttackType, BuildEvent, Effect, Event, EventType, ExitEvent, FindOptions,
-        HarvestEvent, HealEvent, HealType, LookResult, ObjectDestroyedEvent, Path,
-        PositionedLookResult, RepairEvent, ReserveControllerEvent, Step, UpgradeControllerEvent,
+        effective_damage, pack_path, unpack_path, AttackEvent, AttackType, BuildEvent, Effect,
+        Event, EventType, ExitEvent, FindOptions, FindRoomOptions, HarvestEvent, HealEvent,
+        HealType, LookResult, ObjectDestroyedEvent, Path, PositionedLookResult, RepairEvent,
+        ReserveControllerEvent, Step, ThreatSummary, UpgradeControllerEvent,
     },
+    room_snapshot::RoomSnapshot,
     room_visual::{
         CircleStyle, FontStyle, LineDrawStyle, LineStyle, PolyStyle, RectStyle, RoomVisual,
         TextAlign, TextStyle, Visual,
     },
+    source::{harvest_yield, open_positions},
     structure_controller::{Reservation, Sign},
+    structure_index::StructureIndex,
+    structure_lab::{can_run_reaction, LabCooldownTracker},
+    structure_link::balance_links,
+    structure_observer::ObserverSchedule,
     structure_portal::PortalDestination,
-    structure_spawn::SpawnOptions,
+    structure_power_bank::power_bank_hitback_damage,
+    structure_spawn::{BodyScaler, MoveSpeed, SpawnError, SpawnOptions},
+    structure_terminal::SendError,
 };
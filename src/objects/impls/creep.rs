@@ -1,45 +1,250 @@
 use stdweb::Value;
 
 use crate::{
-    constants::{Part, ResourceType, ReturnCode},
+    constants::{
+        Direction, Part, ResourceType, ReturnCode, Terrain, CARRY_CAPACITY, CREEP_LIFE_TIME,
+        CREEP_SPAWN_TIME, SPAWN_RENEW_RATIO,
+    },
+    local::{Position, RoomName},
     objects::{
-        Attackable, ConstructionSite, Creep, Harvestable, SharedCreepProperties,
-        StructureController, StructureProperties, Transferable, Withdrawable,
+        Attackable, ConstructionSite, Creep, Harvestable, HasPosition, MoveToOptions, Resource,
+        SharedCreepProperties, StructureController, StructureProperties, Transferable,
+        Withdrawable,
     },
+    pathfinder::{CostMatrix, SingleRoomCostResult},
     traits::TryFrom,
 };
 
 impl Creep {
+    /// This creep's body parts, in order, with their remaining hits and boosts.
     pub fn body(&self) -> Vec<Bodypart> {
-        // Has to be deconstructed manually to avoid converting strings from js to rust
-        let len: u32 = js_unwrap!(@{self.as_ref()}.body.length);
-        let mut body_parts: Vec<Bodypart> = Vec::with_capacity(len as usize);
-
-        for i in 0..len {
-            let boost_v = js!(const b=@{self.as_ref()}.body[@{i}].boost||null;return b&&__resource_type_str_to_num(b););
-            let boost = match boost_v {
-                Value::Number(_) => {
-                    Some(ResourceType::try_from(boost_v).expect("Creep boost resource unknown."))
-                }
-                _ => None,
-            };
-            let part: Part = js_unwrap!(__part_str_to_num(@{self.as_ref()}.body[@{i}].type));
-            let hits: u32 = js_unwrap!(@{self.as_ref()}.body[@{i}].hits);
-
-            body_parts.push(Bodypart {
-                boost,
+        // decoded one field at a time to avoid converting strings from js to rust
+        let creep = self.as_ref();
+        let parts: Vec<Part> =
+            js_unwrap!(@{creep}.body.map(function(p) { return __part_str_to_num(p.type); }));
+        let hits: Vec<u32> = js_unwrap!(@{creep}.body.map(function(p) { return p.hits; }));
+        let boosts: Vec<Value> = js_unwrap!(@{creep}.body.map(function(p) {
+            return p.boost ? __resource_type_str_to_num(p.boost) : null;
+        }));
+
+        parts
+            .into_iter()
+            .zip(hits)
+            .zip(boosts)
+            .map(|((part, hits), boost)| Bodypart {
+                boost: match boost {
+                    Value::Number(_) => {
+                        Some(ResourceType::try_from(boost).expect("Creep boost resource unknown."))
+                    }
+                    _ => None,
+                },
                 part,
                 hits,
                 _non_exhaustive: (),
-            });
+            })
+            .collect()
+    }
+
+    /// Moves toward `target` like [`SharedCreepProperties::move_to_with_options`],
+    /// forcing a fresh path around other creeps when this creep is stuck.
+    ///
+    /// `state` should be kept across ticks for this creep. Each call records
+    /// the creep's position; once it has stayed in place without fatigue for
+    /// more than [`MoveState::threshold`] ticks, this call moves with
+    /// `reuse_path(0)` and `ignore_creeps(false)`, then resets the stuck count.
+    pub fn smart_move_to<'a, F, T>(
+        &self,
+        target: &T,
+        opts: MoveToOptions<'a, F>,
+        state: &mut MoveState,
+    ) -> ReturnCode
+    where
+        T: ?Sized + HasPosition,
+        F: FnMut(RoomName, CostMatrix<'a>) -> SingleRoomCostResult<'a> + 'a,
+    {
+        if state.record(self.pos(), self.fatigue() > 0) {
+            state.stuck_ticks = 0;
+            self.move_to_with_options(target, opts.reuse_path(0).ignore_creeps(false))
+        } else {
+            self.move_to_with_options(target, opts)
         }
-        body_parts
     }
 
     pub fn sign_controller(&self, target: &StructureController, text: &str) -> ReturnCode {
         js_unwrap!(@{self.as_ref()}.signController(@{target.as_ref()}, @{text}))
     }
 
+    /// Guesses this creep's role from all of its body parts, including
+    /// damaged ones.
+    ///
+    /// See [`InferredRole::from_body`].
+    pub fn infer_role(&self) -> InferredRole {
+        InferredRole::from_body(self.body().into_iter().map(|p| p.part))
+    }
+
+    /// Gets this creep's ticks to live, or `default` while it's still
+    /// spawning and has none.
+    pub fn ticks_to_live_or(&self, default: u32) -> u32 {
+        self.ticks_to_live().unwrap_or(default)
+    }
+
+    /// Whether [`StructureSpawn::renew_creep`] could currently renew this
+    /// creep.
+    ///
+    /// Creeps which are spawning or have `CLAIM` parts can't be renewed, and
+    /// renewing fails if it would raise the creep's ticks to live above
+    /// [`CREEP_LIFE_TIME`]. Doesn't check spawn energy or range.
+    ///
+    /// [`StructureSpawn::renew_creep`]: crate::objects::StructureSpawn::renew_creep
+    pub fn is_renewable(&self) -> bool {
+        if self.spawning() {
+            return false;
+        }
+        let body = self.body();
+        if body.iter().any(|p| p.part == Part::Claim) {
+            return false;
+        }
+        match self.ticks_to_live() {
+            Ok(ttl) => ttl + renew_ticks(body.len() as u32) <= CREEP_LIFE_TIME,
+            Err(_) => false,
+        }
+    }
+
+    /// Moves one tile in `dir` like [`SharedCreepProperties::move_direction`],
+    /// but returns [`ReturnCode::Tired`] without issuing the intent if this
+    /// creep is fatigued.
+    pub fn try_move(&self, dir: Direction) -> ReturnCode {
+        if self.fatigue() > 0 {
+            ReturnCode::Tired
+        } else {
+            self.move_direction(dir)
+        }
+    }
+
+    /// Transfers to `target` like [`Creep::transfer_amount`] (or
+    /// [`Creep::transfer_all`] when `amount` is `None`), moving toward it with
+    /// `opts` instead if it's out of range.
+    ///
+    /// Returns the transfer's result, or the `move_to` result when moving.
+    pub fn transfer_or_move<'a, F, T>(
+        &self,
+        target: &T,
+        ty: ResourceType,
+        amount: Option<u32>,
+        opts: MoveToOptions<'a, F>,
+    ) -> ReturnCode
+    where
+        T: ?Sized + Transferable,
+        F: FnMut(RoomName, CostMatrix<'a>) -> SingleRoomCostResult<'a> + 'a,
+    {
+        let result = match amount {
+            Some(amount) => self.transfer_amount(target, ty, amount),
+            None => self.transfer_all(target, ty),
+        };
+        or_move(result, || self.move_to_with_options(&target.pos(), opts))
+    }
+
+    /// Withdraws from `target` like [`Creep::withdraw_amount`] (or
+    /// [`Creep::withdraw_all`] when `amount` is `None`), moving toward it with
+    /// `opts` instead if it's out of range.
+    ///
+    /// Returns the withdrawal's result, or the `move_to` result when moving.
+    pub fn withdraw_or_move<'a, F, T>(
+        &self,
+        target: &T,
+        ty: ResourceType,
+        amount: Option<u32>,
+        opts: MoveToOptions<'a, F>,
+    ) -> ReturnCode
+    where
+        T: ?Sized + Withdrawable,
+        F: FnMut(RoomName, CostMatrix<'a>) -> SingleRoomCostResult<'a> + 'a,
+    {
+        let result = match amount {
+            Some(amount) => self.withdraw_amount(target, ty, amount),
+            None => self.withdraw_all(target, ty),
+        };
+        or_move(result, || self.move_to_with_options(&target.pos(), opts))
+    }
+
+    /// Picks up `target`, moving toward it with `opts` instead if it's out of
+    /// range.
+    pub fn pickup_or_move<'a, F>(&self, target: &Resource, opts: MoveToOptions<'a, F>) -> ReturnCode
+    where
+        F: FnMut(RoomName, CostMatrix<'a>) -> SingleRoomCostResult<'a> + 'a,
+    {
+        or_move(self.pickup(target), || {
+            self.move_to_with_options(target, opts)
+        })
+    }
+
+    /// Harvests `target`, moving toward it with `opts` instead if it's out of
+    /// range.
+    pub fn harvest_or_move<'a, F, T>(&self, target: &T, opts: MoveToOptions<'a, F>) -> ReturnCode
+    where
+        T: ?Sized + Harvestable,
+        F: FnMut(RoomName, CostMatrix<'a>) -> SingleRoomCostResult<'a> + 'a,
+    {
+        or_move(self.harvest(target), || {
+            self.move_to_with_options(&target.pos(), opts)
+        })
+    }
+
+    /// Builds `target`, moving toward it with `opts` instead if it's out of
+    /// range.
+    pub fn build_or_move<'a, F>(
+        &self,
+        target: &ConstructionSite,
+        opts: MoveToOptions<'a, F>,
+    ) -> ReturnCode
+    where
+        F: FnMut(RoomName, CostMatrix<'a>) -> SingleRoomCostResult<'a> + 'a,
+    {
+        or_move(self.build(target), || {
+            self.move_to_with_options(target, opts)
+        })
+    }
+
+    /// Repairs `target`, moving toward it with `opts` instead if it's out of
+    /// range.
+    pub fn repair_or_move<'a, F, T>(&self, target: &T, opts: MoveToOptions<'a, F>) -> ReturnCode
+    where
+        T: ?Sized + StructureProperties,
+        F: FnMut(RoomName, CostMatrix<'a>) -> SingleRoomCostResult<'a> + 'a,
+    {
+        or_move(self.repair(target), || {
+            self.move_to_with_options(&target.pos(), opts)
+        })
+    }
+
+    /// Upgrades `target`, moving toward it with `opts` instead if it's out of
+    /// range.
+    pub fn upgrade_controller_or_move<'a, F>(
+        &self,
+        target: &StructureController,
+        opts: MoveToOptions<'a, F>,
+    ) -> ReturnCode
+    where
+        F: FnMut(RoomName, CostMatrix<'a>) -> SingleRoomCostResult<'a> + 'a,
+    {
+        or_move(self.upgrade_controller(target), || {
+            self.move_to_with_options(target, opts)
+        })
+    }
+
+    /// Attacks `target`, moving toward it with `opts` instead if it's out of
+    /// range.
+    pub fn attack_or_move<'a, F, T>(&self, target: &T, opts: MoveToOptions<'a, F>) -> ReturnCode
+    where
+        T: ?Sized + Attackable,
+        F: FnMut(RoomName, CostMatrix<'a>) -> SingleRoomCostResult<'a> + 'a,
+    {
+        or_move(self.attack(target), || {
+            self.move_to_with_options(&target.pos(), opts)
+        })
+    }
+
+    /// Number of body parts of type `ty` which still have hits left.
     pub fn get_active_bodyparts(&self, ty: Part) -> u32 {
         js_unwrap!(@{self.as_ref()}.getActiveBodyparts(__part_num_to_str(@{ty as u32})))
     }
@@ -91,10 +296,199 @@ impl Creep {
     }
 }
 
+/// Per-creep state for [`Creep::smart_move_to`], tracking whether the creep
+/// has been stuck in place.
+///
+/// This lives entirely in Rust, so it should be stored somewhere persistent
+/// across ticks, such as a map keyed by creep name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveState {
+    last_pos: Option<Position>,
+    stuck_ticks: u32,
+    threshold: u32,
+}
+
+impl Default for MoveState {
+    fn default() -> Self {
+        MoveState::new(2)
+    }
+}
+
+impl MoveState {
+    /// Creates a new state which forces a repath after the creep has been
+    /// stuck for more than `threshold` ticks.
+    pub fn new(threshold: u32) -> Self {
+        MoveState {
+            last_pos: None,
+            stuck_ticks: 0,
+            threshold,
+        }
+    }
+
+    /// Number of ticks the creep may stay in place before forcing a repath.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Number of consecutive ticks the creep has been recorded as stuck.
+    pub fn stuck_ticks(&self) -> u32 {
+        self.stuck_ticks
+    }
+
+    /// Records the creep's position for this tick, returning whether it's now
+    /// been stuck for longer than the threshold.
+    ///
+    /// Ticks spent fatigued don't count as being stuck, as the creep couldn't
+    /// have moved anyways.
+    pub fn record(&mut self, pos: Position, fatigued: bool) -> bool {
+        if self.last_pos == Some(pos) && !fatigued {
+            self.stuck_ticks += 1;
+        } else if self.last_pos != Some(pos) {
+            self.stuck_ticks = 0;
+        }
+        self.last_pos = Some(pos);
+
+        self.stuck_ticks > self.threshold
+    }
+}
+
+/// Runs `move_to` if an action's `result` was out of range, returning its
+/// result instead.
+fn or_move(result: ReturnCode, move_to: impl FnOnce() -> ReturnCode) -> ReturnCode {
+    if result == ReturnCode::NotInRange {
+        move_to()
+    } else {
+        result
+    }
+}
+
+/// Ticks added to a creep with `body_size` parts by each renewal.
+fn renew_ticks(body_size: u32) -> u32 {
+    (SPAWN_RENEW_RATIO * CREEP_LIFE_TIME as f32 / CREEP_SPAWN_TIME as f32 / body_size as f32) as u32
+}
+
+/// Counts the parts of `creep` still to be boosted for each desired
+/// `(part, boost)` pair, returning `(part, boost, count)` in the same order.
+///
+/// A part counts as still to be boosted if it has no boost at all; a part can
+/// only ever hold one boost. Entries with nothing left to boost are kept with
+/// a count of 0.
+pub fn unboosted_parts(
+    creep: &Creep,
+    desired_boosts: &[(Part, ResourceType)],
+) -> Vec<(Part, ResourceType, u32)> {
+    count_unboosted(&creep.body(), desired_boosts)
+}
+
+fn count_unboosted(
+    body: &[Bodypart],
+    desired_boosts: &[(Part, ResourceType)],
+) -> Vec<(Part, ResourceType, u32)> {
+    desired_boosts
+        .iter()
+        .map(|&(part, boost)| {
+            let count = body
+                .iter()
+                .filter(|p| p.part == part && p.boost.is_none())
+                .count() as u32;
+            (part, boost, count)
+        })
+        .collect()
+}
+
+/// Fatigue generated by one step of a creep with `body` carrying `carried`
+/// resources, onto a tile of the given terrain.
+///
+/// Every part except `MOVE` adds 1 fatigue per step on roads, 2 on plains and
+/// 10 on swamps, except `CARRY` parts which only count while they hold
+/// something. Carried resources fill `CARRY` parts [`CARRY_CAPACITY`] at a time.
+///
+/// Boosted `CARRY` capacity isn't accounted for.
+pub fn fatigue_per_step(body: &[Part], terrain: Terrain, on_road: bool, carried: u32) -> u32 {
+    let factor = if on_road {
+        1
+    } else {
+        match terrain {
+            Terrain::Swamp => 10,
+            Terrain::Plain | Terrain::Wall => 2,
+        }
+    };
+
+    let carry = body.iter().filter(|&&p| p == Part::Carry).count() as u32;
+    let loaded_carry = carried.div_ceil(CARRY_CAPACITY).min(carry);
+    let other = body
+        .iter()
+        .filter(|&&p| p != Part::Move && p != Part::Carry)
+        .count() as u32;
+
+    (other + loaded_carry) * factor
+}
+
+/// Fatigue a creep with `body` is left with after one step, following
+/// [`fatigue_per_step`]: the fatigue generated minus 2 for each `MOVE` part.
+///
+/// A result of 0 or less means the creep can move every tick on this terrain.
+///
+/// Boosted `MOVE` parts aren't accounted for.
+pub fn net_fatigue_per_step(body: &[Part], terrain: Terrain, on_road: bool, carried: u32) -> i32 {
+    let move_parts = body.iter().filter(|&&p| p == Part::Move).count() as i32;
+    fatigue_per_step(body, terrain, on_road, carried) as i32 - move_parts * 2
+}
+
+/// Role guessed from a creep's body by [`Creep::infer_role`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InferredRole {
+    /// Has `CLAIM` parts.
+    Claimer,
+    /// Has at least as many `ATTACK`, `RANGED_ATTACK` and `HEAL` parts as
+    /// `WORK` and `CARRY` parts combined.
+    Military,
+    /// Has `WORK` parts.
+    Worker,
+    /// Has `CARRY` but no `WORK` parts.
+    Hauler,
+    /// Has none of the above, such as a `MOVE`-only scout.
+    Other,
+}
+
+impl InferredRole {
+    /// Classifies a body, checking each role in the order they're declared.
+    pub fn from_body<I>(body: I) -> Self
+    where
+        I: IntoIterator<Item = Part>,
+    {
+        let (mut claim, mut military, mut work, mut carry) = (0, 0, 0, 0);
+        for part in body {
+            match part {
+                Part::Claim => claim += 1,
+                Part::Attack | Part::RangedAttack | Part::Heal => military += 1,
+                Part::Work => work += 1,
+                Part::Carry => carry += 1,
+                Part::Move | Part::Tough => {}
+            }
+        }
+
+        if claim > 0 {
+            InferredRole::Claimer
+        } else if military > 0 && military >= work + carry {
+            InferredRole::Military
+        } else if work > 0 {
+            InferredRole::Worker
+        } else if carry > 0 {
+            InferredRole::Hauler
+        } else {
+            InferredRole::Other
+        }
+    }
+}
+
+/// A single body part of a creep, from [`Creep::body`].
 #[derive(Clone, Debug)]
 pub struct Bodypart {
+    /// The compound boosting this part, if any.
     pub boost: Option<ResourceType>,
     pub part: Part,
+    /// Remaining hits, from `0` (destroyed) to `100`.
     pub hits: u32,
     _non_exhaustive: (),
 }
@@ -108,25 +502,171 @@ simple_accessors! {
 
 creep_simple_generic_action! {
     impl Creep {
+        /// Attacks an adjacent creep, power creep or structure.
         pub fn attack(Attackable) = attack();
+        /// Dismantles an adjacent structure, recovering some of its energy.
         pub fn dismantle(StructureProperties) = dismantle();
+        /// Harvests an adjacent source, mineral or deposit.
         pub fn harvest(Harvestable) = harvest();
+        /// Heals an adjacent creep or power creep.
         pub fn heal(SharedCreepProperties) = heal();
+        /// Attacks a creep, power creep or structure up to 3 tiles away.
         pub fn ranged_attack(Attackable) = rangedAttack();
+        /// Heals a creep or power creep up to 3 tiles away.
         pub fn ranged_heal(SharedCreepProperties) = rangedHeal();
+        /// Repairs a structure up to 3 tiles away, spending energy.
         pub fn repair(StructureProperties) = repair();
     }
 }
 
 creep_simple_concrete_action! {
     impl Creep {
+        /// Reduces the reservation or downgrade timer of an adjacent hostile controller.
         pub fn attack_controller(StructureController) = attackController();
+        /// Builds a construction site up to 3 tiles away, spending energy.
         pub fn build(ConstructionSite) = build();
+        /// Claims an adjacent neutral controller.
         pub fn claim_controller(StructureController) = claimController();
+        /// Adds a safe mode activation to an adjacent controller, spending ghodium.
         pub fn generate_safe_mode(StructureController) = generateSafeMode();
+        /// Moves toward a creep pulling this one, which must have called [`Creep::pull`] on it.
         pub fn move_pulled_by(Creep) = move();
+        /// Pulls an adjacent creep, which must call [`Creep::move_pulled_by`] to follow.
         pub fn pull(Creep) = pull();
+        /// Reserves an adjacent neutral controller.
         pub fn reserve_controller(StructureController) = reserveController();
+        /// Upgrades a controller up to 3 tiles away, spending energy.
         pub fn upgrade_controller(StructureController) = upgradeController();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        count_unboosted, fatigue_per_step, net_fatigue_per_step, or_move, renew_ticks, Bodypart,
+        InferredRole, MoveState,
+    };
+    use crate::{
+        constants::{Part, ResourceType, ReturnCode, Terrain},
+        local::Position,
+    };
+
+    #[test]
+    fn move_state_detects_stuck() {
+        let room = "E1N1".parse().unwrap();
+        let a = Position::new(10, 10, room);
+        let b = Position::new(11, 10, room);
+
+        let mut state = MoveState::new(2);
+        assert!(!state.record(a, false));
+        assert!(!state.record(a, false));
+        // fatigue doesn't count toward being stuck
+        assert!(!state.record(a, true));
+        assert!(!state.record(a, false));
+        assert!(state.record(a, false));
+        assert!(!state.record(b, false));
+        assert_eq!(state.stuck_ticks(), 0);
+    }
+
+    #[test]
+    fn infer_role_from_body() {
+        use Part::*;
+
+        assert_eq!(
+            InferredRole::from_body(vec![Claim, Move, Work]),
+            InferredRole::Claimer
+        );
+        assert_eq!(
+            InferredRole::from_body(vec![Tough, Attack, Heal, Move]),
+            InferredRole::Military
+        );
+        assert_eq!(
+            InferredRole::from_body(vec![Work, Carry, Attack, Move]),
+            InferredRole::Worker
+        );
+        assert_eq!(
+            InferredRole::from_body(vec![Work, Work, Move]),
+            InferredRole::Worker
+        );
+        assert_eq!(
+            InferredRole::from_body(vec![Carry, Carry, Move]),
+            InferredRole::Hauler
+        );
+        assert_eq!(InferredRole::from_body(vec![Move]), InferredRole::Other);
+    }
+
+    #[test]
+    fn count_unboosted_skips_boosted_parts() {
+        let part = |part, boost| Bodypart {
+            boost,
+            part,
+            hits: 100,
+            _non_exhaustive: (),
+        };
+        let body = vec![
+            part(Part::Work, Some(ResourceType::LemergiumHydride)),
+            part(Part::Work, None),
+            part(Part::Work, None),
+            part(Part::Move, None),
+        ];
+
+        let remaining = count_unboosted(
+            &body,
+            &[
+                (Part::Work, ResourceType::LemergiumHydride),
+                (Part::Carry, ResourceType::KeaniumHydride),
+            ],
+        );
+        assert_eq!(
+            remaining,
+            vec![
+                (Part::Work, ResourceType::LemergiumHydride, 2),
+                (Part::Carry, ResourceType::KeaniumHydride, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn renew_ticks_matches_documented_formula() {
+        assert_eq!(renew_ticks(1), 600);
+        assert_eq!(renew_ticks(7), 85);
+        assert_eq!(renew_ticks(50), 12);
+    }
+
+    #[test]
+    fn fatigue_generation() {
+        use Part::*;
+        let hauler = [Carry, Carry, Move];
+
+        assert_eq!(fatigue_per_step(&hauler, Terrain::Plain, false, 0), 0);
+        assert_eq!(fatigue_per_step(&hauler, Terrain::Plain, false, 50), 2);
+        assert_eq!(fatigue_per_step(&hauler, Terrain::Plain, false, 51), 4);
+        assert_eq!(fatigue_per_step(&hauler, Terrain::Plain, false, 500), 4);
+        assert_eq!(fatigue_per_step(&hauler, Terrain::Swamp, false, 100), 20);
+        assert_eq!(fatigue_per_step(&hauler, Terrain::Swamp, true, 100), 2);
+
+        assert_eq!(net_fatigue_per_step(&hauler, Terrain::Plain, false, 100), 2);
+        assert_eq!(net_fatigue_per_step(&hauler, Terrain::Plain, true, 100), 0);
+        assert_eq!(
+            net_fatigue_per_step(&[Work, Move], Terrain::Plain, false, 0),
+            0
+        );
+        assert_eq!(net_fatigue_per_step(&[Move], Terrain::Swamp, false, 0), -2);
+    }
+
+    #[test]
+    fn moves_only_when_out_of_range() {
+        let mut moved = false;
+        let result = or_move(ReturnCode::NotInRange, || {
+            moved = true;
+            ReturnCode::Ok
+        });
+        assert!(moved);
+        assert_eq!(result, ReturnCode::Ok);
+
+        for &code in &[ReturnCode::Ok, ReturnCode::NotEnough, ReturnCode::Full] {
+            let result = or_move(code, || panic!("moved on {:?}", code));
+            assert_eq!(result, code);
+        }
+    }
+}
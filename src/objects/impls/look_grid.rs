@@ -0,0 +1,153 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    local::RawObjectId,
+    objects::{HasId, LookResult, PositionedLookResult, Room},
+};
+
+impl LookResult {
+    /// The id of the object found, or `None` for terrain and flags, which
+    /// have no ids.
+    pub fn untyped_id(&self) -> Option<RawObjectId> {
+        match self {
+            LookResult::Creep(o) => Some(o.untyped_id()),
+            LookResult::Energy(o) | LookResult::Resource(o) => Some(o.untyped_id()),
+            LookResult::Source(o) => Some(o.untyped_id()),
+            LookResult::Mineral(o) => Some(o.untyped_id()),
+            LookResult::Deposit(o) => Some(o.untyped_id()),
+            LookResult::Structure(o) => Some(o.untyped_id()),
+            LookResult::ConstructionSite(o) => Some(o.untyped_id()),
+            LookResult::Nuke(o) => Some(o.untyped_id()),
+            LookResult::Tombstone(o) => Some(o.untyped_id()),
+            LookResult::PowerCreep(o) => Some(o.untyped_id()),
+            LookResult::Ruin(o) => Some(o.untyped_id()),
+            LookResult::Flag(_) | LookResult::Terrain(_) => None,
+        }
+    }
+}
+
+/// Ids of the objects on each tile of an area scan, for detecting changes
+/// between scans with [`diff_look_grids`].
+///
+/// Terrain and flags, which have no ids, aren't included.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LookGrid {
+    tiles: BTreeMap<(u32, u32), BTreeSet<RawObjectId>>,
+}
+
+impl LookGrid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a grid from the results of [`Room::look_at_area`].
+    pub fn from_results(results: &[PositionedLookResult]) -> Self {
+        let mut grid = LookGrid::new();
+        for result in results {
+            if let Some(id) = result.look_result.untyped_id() {
+                grid.insert(result.x, result.y, id);
+            }
+        }
+        grid
+    }
+
+    /// Records an object with id `id` at `x`, `y`.
+    pub fn insert(&mut self, x: u32, y: u32, id: RawObjectId) {
+        self.tiles.entry((x, y)).or_default().insert(id);
+    }
+
+    /// Ids of the objects at `x`, `y`.
+    pub fn at(&self, x: u32, y: u32) -> impl Iterator<Item = RawObjectId> + '_ {
+        self.tiles.get(&(x, y)).into_iter().flatten().copied()
+    }
+}
+
+impl Room {
+    /// Scans an area with [`Room::look_at_area`] into a [`LookGrid`].
+    pub fn look_grid(&self, top: u32, left: u32, bottom: u32, right: u32) -> LookGrid {
+        LookGrid::from_results(&self.look_at_area(top, left, bottom, right))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LookChange {
+    Added,
+    Removed,
+}
+
+/// An object appearing on or disappearing from a tile between two
+/// [`LookGrid`]s.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LookDelta {
+    pub x: u32,
+    pub y: u32,
+    pub id: RawObjectId,
+    pub change: LookChange,
+}
+
+/// Lists the objects added to and removed from each tile between the `old`
+/// and `new` scans, ordered by tile.
+///
+/// An object which moved shows up as removed from its old tile and added to
+/// its new one.
+pub fn diff_look_grids(old: &LookGrid, new: &LookGrid) -> Vec<LookDelta> {
+    let empty = BTreeSet::new();
+    let tiles: BTreeSet<&(u32, u32)> = old.tiles.keys().chain(new.tiles.keys()).collect();
+
+    let mut deltas = Vec::new();
+    for &(x, y) in tiles {
+        let before = old.tiles.get(&(x, y)).unwrap_or(&empty);
+        let after = new.tiles.get(&(x, y)).unwrap_or(&empty);
+        let delta = |id: &RawObjectId, change| LookDelta {
+            x,
+            y,
+            id: *id,
+            change,
+        };
+        deltas.extend(
+            before
+                .difference(after)
+                .map(|id| delta(id, LookChange::Removed)),
+        );
+        deltas.extend(
+            after
+                .difference(before)
+                .map(|id| delta(id, LookChange::Added)),
+        );
+    }
+    deltas
+}
+
+#[cfg(test)]
+mod test {
+    use super::{diff_look_grids, LookChange, LookGrid};
+    use crate::local::RawObjectId;
+
+    #[test]
+    fn diff_reports_added_and_removed() {
+        let id = |s: &str| s.parse::<RawObjectId>().unwrap();
+        let (a, b, c) = (id("aaaa"), id("bbbb"), id("cccc"));
+
+        let mut old = LookGrid::new();
+        old.insert(1, 1, a);
+        old.insert(2, 2, b);
+        let mut new = LookGrid::new();
+        new.insert(1, 1, a);
+        new.insert(3, 3, b);
+        new.insert(3, 3, c);
+
+        let changes: Vec<_> = diff_look_grids(&old, &new)
+            .into_iter()
+            .map(|d| (d.x, d.y, d.id, d.change))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (2, 2, b, LookChange::Removed),
+                (3, 3, b, LookChange::Added),
+                (3, 3, c, LookChange::Added),
+            ]
+        );
+        assert!(diff_look_grids(&new, &new).is_empty());
+    }
+}
@@ -72,6 +72,22 @@ impl AccountPowerCreep {
         js_unwrap!(@{self.as_ref()}.upgrade(@{power_type as u32}))
     }
 
+    /// Whether this power creep is currently spawned on any shard.
+    pub fn is_spawned(&self) -> bool {
+        self.shard().is_some()
+    }
+
+    /// Whether this power creep is unspawned, and its spawn cooldown (if any)
+    /// has run out, so it can be spawned at a power spawn.
+    pub fn is_ready_to_spawn(&self) -> bool {
+        if self.is_spawned() {
+            return false;
+        }
+        let now: f64 = js_unwrap!(Date.now());
+        self.spawn_cooldown_time()
+            .is_none_or(|ready_at| ready_at as f64 <= now)
+    }
+
     /// Convert this `AccountPowerCreep`, which can represent either a spawned
     /// or unspawned power creep, into a full `PowerCreep` object
     /// representation
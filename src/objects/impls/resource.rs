@@ -1,4 +1,7 @@
-use crate::{constants::ResourceType, objects::Resource};
+use crate::{
+    constants::{ResourceType, ENERGY_DECAY},
+    objects::{CanDecay, HasPosition, HasStore, Resource, Ruin, Tombstone},
+};
 
 impl Resource {
     pub fn resource_type(&self) -> ResourceType {
@@ -11,3 +14,118 @@ simple_accessors! {
         pub fn amount() -> u32 = amount;
     }
 }
+
+/// A place to gather a resource from, as chosen by [`best_pickup`].
+#[derive(Clone, Copy)]
+pub enum PickupTarget<'a> {
+    /// A dropped resource, to be gathered with `pickup`.
+    Resource(&'a Resource),
+    /// A tombstone, to be gathered with `withdraw`.
+    Tombstone(&'a Tombstone),
+    /// A ruin, to be gathered with `withdraw`.
+    Ruin(&'a Ruin),
+}
+
+/// Picks the dropped resource, tombstone or ruin from which the most of
+/// `resource` can be gathered fastest from `pos`.
+///
+/// Each candidate is scored by the amount expected to still be there on
+/// arrival divided by the travel distance, with a bonus for candidates about
+/// to disappear. Candidates which will have decayed before a creep could reach
+/// them are skipped.
+pub fn best_pickup<'a>(
+    pos: &impl HasPosition,
+    dropped: &'a [Resource],
+    tombstones: &'a [Tombstone],
+    ruins: &'a [Ruin],
+    resource: ResourceType,
+) -> Option<PickupTarget<'a>> {
+    let pos = pos.pos();
+
+    let dropped = dropped
+        .iter()
+        .filter(|r| r.resource_type() == resource)
+        .map(|r| {
+            let amount = r.amount();
+            let per_tick = dropped_decay_per_tick(amount);
+            let score = pickup_score(amount, per_tick, pos.get_range_to(r), amount / per_tick);
+            (score, PickupTarget::Resource(r))
+        });
+    let tombstones = tombstones.iter().map(|t| {
+        let score = pickup_score(
+            t.store_of(resource),
+            0,
+            pos.get_range_to(t),
+            t.ticks_to_decay(),
+        );
+        (score, PickupTarget::Tombstone(t))
+    });
+    let ruins = ruins.iter().map(|r| {
+        let score = pickup_score(
+            r.store_of(resource),
+            0,
+            pos.get_range_to(r),
+            r.ticks_to_decay(),
+        );
+        (score, PickupTarget::Ruin(r))
+    });
+
+    dropped
+        .chain(tombstones)
+        .chain(ruins)
+        .filter(|(score, _)| *score > 0.0)
+        .fold(
+            None,
+            |best: Option<(f64, PickupTarget<'a>)>, next| match best {
+                Some(best) if best.0 >= next.0 => Some(best),
+                _ => Some(next),
+            },
+        )
+        .map(|(_, target)| target)
+}
+
+/// Amount a dropped resource pile of `amount` loses each tick.
+fn dropped_decay_per_tick(amount: u32) -> u32 {
+    amount.div_ceil(ENERGY_DECAY).max(1)
+}
+
+/// Ticks-left-on-arrival under which a candidate gets an urgency bonus.
+const URGENCY_WINDOW: f64 = 10.0;
+
+/// Scores a pickup candidate holding `amount`, losing `decay_per_tick` each
+/// tick, `range` tiles away and gone after `ticks_to_decay` ticks.
+fn pickup_score(amount: u32, decay_per_tick: u32, range: u32, ticks_to_decay: u32) -> f64 {
+    if ticks_to_decay <= range {
+        return 0.0;
+    }
+    let on_arrival = amount.saturating_sub(decay_per_tick.saturating_mul(range));
+    let slack = f64::from(ticks_to_decay - range);
+    let urgency = 1.0 + URGENCY_WINDOW / (slack + URGENCY_WINDOW);
+    f64::from(on_arrival) / f64::from(range + 1) * urgency
+}
+
+#[cfg(test)]
+mod test {
+    use super::{dropped_decay_per_tick, pickup_score};
+
+    #[test]
+    fn dropped_decay() {
+        assert_eq!(dropped_decay_per_tick(1), 1);
+        assert_eq!(dropped_decay_per_tick(1000), 1);
+        assert_eq!(dropped_decay_per_tick(1001), 2);
+    }
+
+    #[test]
+    fn scores() {
+        // gone before arrival
+        assert_eq!(pickup_score(500, 0, 10, 10), 0.0);
+        // closer is better
+        assert!(pickup_score(500, 0, 2, 1000) > pickup_score(500, 0, 5, 1000));
+        // more is better
+        assert!(pickup_score(800, 0, 5, 1000) > pickup_score(500, 0, 5, 1000));
+        // about to vanish is more urgent
+        assert!(pickup_score(500, 0, 5, 8) > pickup_score(500, 0, 5, 1000));
+        // decay while travelling counts against dropped piles
+        assert!(pickup_score(500, 1, 20, 500) < pickup_score(500, 0, 20, 500));
+    }
+}
@@ -12,18 +12,22 @@ use stdweb::{Reference, Value};
 
 use crate::{
     constants::{
-        Color, Direction, EffectType, ExitDirection, FindConstant, Look, LookConstant, PowerType,
-        ResourceType, ReturnCode, StructureType, Terrain,
+        body_cost, find, look, Color, Direction, EffectType, ExitDirection, FindConstant, Look,
+        LookConstant, NaturalEffectType, Part, PowerType, ResourceType, ReturnCode, StructureType,
+        Terrain, ATTACK_POWER, CONTAINER_DECAY, CONTAINER_DECAY_TIME, CONTAINER_DECAY_TIME_OWNED,
+        DISMANTLE_POWER, HEAL_POWER, RAMPART_DECAY_AMOUNT, RAMPART_DECAY_TIME, RANGED_ATTACK_POWER,
+        REPAIR_COST, ROAD_DECAY_AMOUNT, ROAD_DECAY_TIME, ROAD_HITS,
     },
     local::{Position, RoomName},
     memory::MemoryReference,
     objects::{
-        ConstructionSite, Creep, Deposit, Flag, HasPosition, Mineral, Nuke, PowerCreep, Resource,
-        Room, RoomTerrain, RoomVisual, Ruin, Source, Structure, StructureController,
-        StructureStorage, StructureTerminal, Tombstone,
+        Attackable, ConstructionSite, Creep, Deposit, Flag, HasPosition, HasStore, Mineral, Nuke,
+        OwnedStructureProperties, PowerCreep, Resource, Room, RoomTerrain, RoomVisual, Ruin,
+        Source, Structure, StructureController, StructureLab, StructureProperties, StructureSpawn,
+        StructureStorage, StructureTerminal, StructureTower, Tombstone,
     },
     pathfinder::{CostMatrix, RoomCostResult, SingleRoomCostResult},
-    traits::{TryFrom, TryInto},
+    traits::{FromExpectedType, IntoExpectedType, TryFrom, TryInto},
     ConversionError,
 };
 
@@ -47,6 +51,22 @@ impl Room {
         js_unwrap! {Room.deserializePath(@{path})}
     }
 
+    /// Whether this room currently has enough energy to spawn a creep with
+    /// the given body.
+    pub fn can_spawn_now(&self, body: &[Part]) -> bool {
+        body_cost(body) <= self.energy_available()
+    }
+
+    /// Whether this room could spawn a creep with the given body once its
+    /// spawns and extensions are full.
+    ///
+    /// When this is `true` but [`Room::can_spawn_now`] is `false`, the body
+    /// only needs energy to refill; when this is `false`, it can't be spawned
+    /// in this room at its current capacity.
+    pub fn will_be_able_to_spawn(&self, body: &[Part]) -> bool {
+        body_cost(body) <= self.energy_capacity_available()
+    }
+
     pub fn create_construction_site<T>(&self, at: &T, ty: StructureType) -> ReturnCode
     where
         T: ?Sized + HasPosition,
@@ -108,17 +128,155 @@ impl Room {
         js_unwrap_ref!(@{self.as_ref()}.find(@{ty.find_code()}))
     }
 
-    pub fn find_exit_to(&self, room: &Room) -> Result<ExitDirection, ReturnCode> {
-        let code_val = js! {return @{self.as_ref()}.findExitTo(@{room.as_ref()});};
-        let code_int: i32 = code_val.try_into().unwrap();
+    /// Finds objects like [`Room::find`], keeping only those matching the
+    /// filter in `opts`.
+    ///
+    /// The filter is passed to the game's `find` as its `filter` option and
+    /// called once per candidate, which is converted into a Rust object for
+    /// it; candidates which fail to convert are skipped.
+    pub fn find_with_options<T, F>(&self, ty: T, opts: FindRoomOptions<F>) -> Vec<T::Item>
+    where
+        T: FindConstant,
+        F: FnMut(&T::Item) -> bool,
+    {
+        let FindRoomOptions { filter } = opts;
+        let mut raw_filter = filter;
 
-        if code_int < 0 {
-            Err(ReturnCode::from_i32(code_int)
-                .expect("expected find_exit_to return value < 0 to be a valid ReturnCode"))
-        } else {
-            Ok(ExitDirection::from_i32(code_int)
-                .expect("expected find_exit_to return value >= 0 to be a valid Exit"))
-        }
+        let mut callback_boxed = move |candidate: Reference| -> bool {
+            match T::Item::from_expected_type(candidate) {
+                Ok(item) => raw_filter(&item),
+                Err(_) => false,
+            }
+        };
+
+        // Type erased and boxed callback: no longer a type specific to the closure
+        // passed in, now unified as &Fn
+        let callback_type_erased: &mut dyn FnMut(Reference) -> bool = &mut callback_boxed;
+
+        // Overwrite lifetime of reference so it can be passed to javascript.
+        // It's now pretending to be static data. This should be entirely safe
+        // because we control the only use of it and it remains valid during the
+        // find call. This transmute is necessary because "some lifetime above
+        // the current scope but otherwise unknown" is not a valid lifetime.
+        let callback_lifetime_erased: &'static mut dyn FnMut(Reference) -> bool =
+            unsafe { mem::transmute(callback_type_erased) };
+
+        let res = js! {
+            let cb = @{callback_lifetime_erased};
+            let res = @{self.as_ref()}.find(@{ty.find_code()}, { filter: cb });
+            cb.drop();
+            return res;
+        };
+
+        res.into_expected_type()
+            .expect("expected Room.find to return an array of the expected type")
+    }
+
+    /// Counts the objects [`Room::find`] would return, without wrapping each
+    /// one in a Rust object.
+    ///
+    /// The underlying JavaScript `find` still runs at the same cost; this only
+    /// saves the per-object conversion and allocation on the Rust side.
+    pub fn count<T>(&self, ty: T) -> usize
+    where
+        T: FindConstant,
+    {
+        let count: u32 = js_unwrap!(@{self.as_ref()}.find(@{ty.find_code()}).length);
+        count as usize
+    }
+
+    /// Finds all spawns owned by you in this room.
+    pub fn spawns(&self) -> Vec<StructureSpawn> {
+        self.find(find::MY_SPAWNS)
+    }
+
+    /// Finds all towers owned by you in this room.
+    pub fn towers(&self) -> Vec<StructureTower> {
+        self.find(find::MY_STRUCTURES)
+            .into_iter()
+            .filter_map(|s| match s.as_structure() {
+                Structure::Tower(tower) => Some(tower),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Finds all labs owned by you in this room.
+    pub fn labs(&self) -> Vec<StructureLab> {
+        self.find(find::MY_STRUCTURES)
+            .into_iter()
+            .filter_map(|s| match s.as_structure() {
+                Structure::Lab(lab) => Some(lab),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Total energy held in this room's storage, terminal and containers.
+    pub fn total_energy(&self) -> u32 {
+        self.total_resource(ResourceType::Energy)
+    }
+
+    /// Energy in this room's storage, terminal and containers beyond the
+    /// given `reserve`, or 0 when the room holds less than the reserve.
+    ///
+    /// See [`Room::total_energy`].
+    pub fn energy_above_reserve(&self, reserve: u32) -> u32 {
+        self.total_energy().saturating_sub(reserve)
+    }
+
+    /// Whether `amount` energy can be spent without dipping into `reserve`.
+    pub fn can_spend(&self, amount: u32, reserve: u32) -> bool {
+        can_spend_from(self.total_energy(), amount, reserve)
+    }
+
+    /// Energy per tick needed to repair the decay of this room's roads,
+    /// containers and your ramparts, on average.
+    ///
+    /// Road wear from creep traffic isn't included, so busy roads will cost
+    /// more than this.
+    pub fn decay_upkeep_per_tick(&self) -> f64 {
+        let owned_room = self.controller().is_some_and(|c| c.has_owner());
+        self.find(find::STRUCTURES)
+            .into_iter()
+            .map(|s| match s {
+                Structure::Road(road) => {
+                    structure_upkeep(StructureType::Road, road.hits_max(), owned_room)
+                }
+                Structure::Container(container) => {
+                    structure_upkeep(StructureType::Container, container.hits_max(), owned_room)
+                }
+                Structure::Rampart(rampart) if rampart.my() => {
+                    structure_upkeep(StructureType::Rampart, rampart.hits_max(), owned_room)
+                }
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// Total amount of `ty` held in this room's storage, terminal and
+    /// containers.
+    ///
+    /// Containers are found with a `find` each call, so cache the result if
+    /// it's needed more than once per tick.
+    pub fn total_resource(&self, ty: ResourceType) -> u32 {
+        let storage = self.storage().map_or(0, |s| s.store_of(ty));
+        let terminal = self.terminal().map_or(0, |t| t.store_of(ty));
+        let containers: u32 = self
+            .find(find::STRUCTURES)
+            .into_iter()
+            .filter_map(|s| match s {
+                Structure::Container(container) => Some(container.store_of(ty)),
+                _ => None,
+            })
+            .sum();
+
+        storage + terminal + containers
+    }
+
+    pub fn find_exit_to(&self, room: &Room) -> Result<ExitDirection, ReturnCode> {
+        let code: i32 = js_unwrap!(@{self.as_ref()}.findExitTo(@{room.as_ref()}));
+        ExitDirection::from_find_exit_code(code)
     }
 
     pub fn get_event_log(&self) -> Vec<Event> {
@@ -129,6 +287,8 @@ impl Room {
         js_unwrap! {@{self.as_ref()}.getEventLog(true)}
     }
 
+    /// Gets the position at `x`, `y` in this room, or `None` when either
+    /// coordinate is outside `0..=49`.
     pub fn get_position_at(&self, x: u32, y: u32) -> Option<Position> {
         let v = js! {
             let value = @{self.as_ref()}.getPositionAt(@{x}, @{y});
@@ -156,6 +316,8 @@ impl Room {
         js_unwrap!(@{self.as_ref()}.getTerrain())
     }
 
+    /// Lists everything at `target`'s position in this room, already decoded
+    /// into typed [`LookResult`]s.
     pub fn look_at<T: ?Sized + HasPosition>(&self, target: &T) -> Vec<LookResult> {
         let pos = target.pos();
         js_unwrap!(@{self.as_ref()}.lookAt(pos_from_packed(@{pos.packed_repr()})))
@@ -216,6 +378,7 @@ impl Room {
         let FindOptions {
             ignore_creeps,
             ignore_destructible_structures,
+            ignore_roads,
             max_ops,
             heuristic_weight,
             serialize,
@@ -234,6 +397,7 @@ impl Room {
                 {
                     ignoreCreeps: @{ignore_creeps},
                     ignoreDestructibleStructures: @{ignore_destructible_structures},
+                    ignoreRoads: @{ignore_roads},
                     costCallback: cb,
                     maxOps: @{max_ops},
                     heuristicWeight: @{heuristic_weight},
@@ -312,16 +476,54 @@ impl Room {
         assert!(horiz.end <= 50);
         assert!(vert.end <= 50);
 
+        if horiz.start == horiz.end || vert.start == vert.end {
+            return Vec::new();
+        }
+
+        // `lookForAtArea` bounds are inclusive on both ends.
         T::convert_and_check_items(js_unwrap! {@{self.as_ref()}.lookForAtArea(
             __look_num_to_str(@{ty.look_code() as u32}),
             @{vert.start},
             @{horiz.start},
-            @{vert.end},
-            @{horiz.end},
+            @{vert.end - 1},
+            @{horiz.end - 1},
             true
         ).map((obj) => obj[__look_num_to_str(@{ty.look_code() as u32})])})
     }
 
+    /// Whether a creep could currently step onto the tile at `x`, `y`.
+    ///
+    /// Terrain walls are impassable unless a road has been built over them.
+    /// Roads, containers, portals, and ramparts which are yours or public are
+    /// passable; all other structures are not. With `consider_creeps`, tiles
+    /// occupied by creeps or power creeps are also treated as blocked.
+    ///
+    /// Construction sites are not considered.
+    pub fn is_walkable(&self, x: u8, y: u8, consider_creeps: bool) -> bool {
+        let (x, y) = (u32::from(x), u32::from(y));
+        let mut has_road = false;
+        for structure in self.look_for_at_xy(look::STRUCTURES, x, y) {
+            match structure {
+                Structure::Road(_) => has_road = true,
+                Structure::Container(_) | Structure::Portal(_) => {}
+                Structure::Rampart(rampart) => {
+                    if !rampart.my() && !rampart.is_public() {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+
+        if !has_road && self.get_terrain().get(x, y) == Terrain::Wall {
+            return false;
+        }
+
+        !consider_creeps
+            || (self.look_for_at_xy(look::CREEPS, x, y).is_empty()
+                && self.look_for_at_xy(look::POWER_CREEPS, x, y).is_empty())
+    }
+
     pub fn memory(&self) -> MemoryReference {
         js_unwrap!(@{self.as_ref()}.memory)
     }
@@ -333,6 +535,60 @@ impl Room {
     pub fn visual(&self) -> RoomVisual {
         RoomVisual::new(Some(self.name()))
     }
+
+    /// Tallies the active combat parts of all hostile creeps in this room.
+    ///
+    /// Outputs are computed from the unboosted power constants; see
+    /// [`ThreatSummary`].
+    pub fn hostile_threat(&self) -> ThreatSummary {
+        let mut summary = ThreatSummary::default();
+        for creep in self.find(find::HOSTILE_CREEPS) {
+            summary.add_parts(
+                creep.get_active_bodyparts(Part::Attack),
+                creep.get_active_bodyparts(Part::RangedAttack),
+                creep.get_active_bodyparts(Part::Heal),
+                creep.get_active_bodyparts(Part::Work),
+                creep.get_active_bodyparts(Part::Tough),
+            );
+        }
+        summary
+    }
+}
+
+/// Combined active combat parts of a group of creeps, as returned by
+/// [`Room::hostile_threat`].
+///
+/// `damage` and `heal` are per-tick outputs at point-blank range using the
+/// unboosted [`ATTACK_POWER`], [`RANGED_ATTACK_POWER`] and [`HEAL_POWER`]
+/// constants; boosts are not taken into account.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ThreatSummary {
+    pub creeps: u32,
+    pub attack: u32,
+    pub ranged_attack: u32,
+    pub heal: u32,
+    pub work: u32,
+    pub tough: u32,
+    /// Combined melee and ranged damage per tick.
+    pub damage: u32,
+    /// Combined healing per tick.
+    pub heal_power: u32,
+    /// Combined structure damage per tick from dismantling.
+    pub dismantle: u32,
+}
+
+impl ThreatSummary {
+    fn add_parts(&mut self, attack: u32, ranged_attack: u32, heal: u32, work: u32, tough: u32) {
+        self.creeps += 1;
+        self.attack += attack;
+        self.ranged_attack += ranged_attack;
+        self.heal += heal;
+        self.work += work;
+        self.tough += tough;
+        self.damage += attack * ATTACK_POWER + ranged_attack * RANGED_ATTACK_POWER;
+        self.heal_power += heal * HEAL_POWER;
+        self.dismantle += work * DISMANTLE_POWER;
+    }
 }
 
 impl PartialEq for Room {
@@ -343,6 +599,23 @@ impl PartialEq for Room {
 
 impl Eq for Room {}
 
+/// Options for [`Room::find_with_options`].
+pub struct FindRoomOptions<F> {
+    pub(crate) filter: F,
+}
+
+impl<F> FindRoomOptions<F> {
+    /// Creates options keeping only objects for which `filter` returns true.
+    pub fn new(filter: F) -> Self {
+        FindRoomOptions { filter }
+    }
+
+    /// Sets the filter - objects for which it returns false are left out.
+    pub fn filter<F2>(self, filter: F2) -> FindRoomOptions<F2> {
+        FindRoomOptions { filter }
+    }
+}
+
 pub struct FindOptions<'a, F, R>
 where
     F: FnMut(RoomName, CostMatrix<'a>) -> R,
@@ -350,6 +623,7 @@ where
 {
     pub(crate) ignore_creeps: bool,
     pub(crate) ignore_destructible_structures: bool,
+    pub(crate) ignore_roads: bool,
     pub(crate) cost_callback: F,
     pub(crate) max_ops: u32,
     pub(crate) heuristic_weight: f64,
@@ -371,6 +645,7 @@ where
         FindOptions {
             ignore_creeps: false,
             ignore_destructible_structures: false,
+            ignore_roads: false,
             cost_callback: |_, _| R::default(),
             max_ops: 2000,
             heuristic_weight: 1.2,
@@ -412,6 +687,13 @@ where
         self
     }
 
+    /// Sets whether the algorithm ignores roads, treating them like the
+    /// terrain beneath. Default: False.
+    pub fn ignore_roads(mut self, ignore: bool) -> Self {
+        self.ignore_roads = ignore;
+        self
+    }
+
     /// Sets cost callback - default `|_, _| {}`.
     pub fn cost_callback<'b, F2, R2>(self, cost_callback: F2) -> FindOptions<'b, F2, R2>
     where
@@ -421,6 +703,7 @@ where
         let FindOptions {
             ignore_creeps,
             ignore_destructible_structures,
+            ignore_roads,
             max_ops,
             heuristic_weight,
             serialize,
@@ -434,6 +717,7 @@ where
         FindOptions {
             ignore_creeps,
             ignore_destructible_structures,
+            ignore_roads,
             cost_callback,
             max_ops,
             heuristic_weight,
@@ -488,6 +772,7 @@ where
     }
 }
 
+/// A single step of a path returned by [`Room::find_path`].
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Step {
     pub x: u32,
@@ -500,6 +785,8 @@ pub struct Step {
 js_deserializable! {Step}
 js_serializable! {Step}
 
+/// A path returned by [`Room::find_path`]: a list of steps, or the
+/// `Room.serializePath` string when [`FindOptions::serialize`] was set.
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum Path {
@@ -509,6 +796,75 @@ pub enum Path {
 
 js_deserializable! {Path}
 
+/// Packs a path into a compact byte form, for storing in memory.
+///
+/// The format is the `x` and `y` of the tile the path starts from (the tile
+/// before the first step), one byte each, followed by one byte per step
+/// holding its direction as `0..=7` (`Direction::Top` is 0, going clockwise).
+/// An empty path packs to no bytes at all.
+///
+/// Returns `None` if the path doesn't start from a tile inside the room.
+///
+/// Use [`unpack_path`] to get the steps back.
+pub fn pack_path(steps: &[Step]) -> Option<Vec<u8>> {
+    let first = match steps.first() {
+        Some(first) => first,
+        None => return Some(Vec::new()),
+    };
+
+    let mut packed = Vec::with_capacity(steps.len() + 2);
+    packed.push(room_coord(first.x as i32 - first.dx)? as u8);
+    packed.push(room_coord(first.y as i32 - first.dy)? as u8);
+    packed.extend(steps.iter().map(|step| step.direction as u8 - 1));
+    Some(packed)
+}
+
+/// `coord` if it's a valid x or y coordinate within a room.
+fn room_coord(coord: i32) -> Option<u32> {
+    if (0..50).contains(&coord) {
+        Some(coord as u32)
+    } else {
+        None
+    }
+}
+
+/// Unpacks a path packed by [`pack_path`].
+///
+/// Returns `None` if `packed` isn't in the format `pack_path` produces, or if
+/// the path leaves the room.
+pub fn unpack_path(packed: &[u8]) -> Option<Vec<Step>> {
+    let (start, directions) = match packed {
+        [] => return Some(Vec::new()),
+        [x, y, directions @ ..] => (
+            (room_coord(i32::from(*x))?, room_coord(i32::from(*y))?),
+            directions,
+        ),
+        _ => return None,
+    };
+
+    let (mut x, mut y) = start;
+    directions
+        .iter()
+        .map(|&byte| {
+            if byte >= 8 {
+                return None;
+            }
+            let direction = Direction::from_u8(byte + 1)?;
+            let (dx, dy) = direction.offset();
+            let (dx, dy) = (i32::from(dx), i32::from(dy));
+            x = room_coord(x as i32 + dx)?;
+            y = room_coord(y as i32 + dy)?;
+            Some(Step {
+                x,
+                y,
+                dx,
+                dy,
+                direction,
+            })
+        })
+        .collect()
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Event {
     pub event: EventType,
@@ -776,6 +1132,70 @@ pub struct Effect {
 }
 js_deserializable! {Effect}
 
+/// Applies the damage-modifying effects in `effects` to an incoming `base`
+/// amount of damage, returning the damage the object would actually take.
+///
+/// Currently known modifiers:
+///
+/// - [`PowerType::Fortify`] makes a rampart or wall invulnerable while active
+/// - [`NaturalEffectType::Invulnerability`] makes a deploying stronghold
+///   invulnerable
+///
+/// Effects which don't modify incoming damage are ignored.
+///
+/// Usable with [`RoomObjectProperties::effects`].
+///
+/// [`RoomObjectProperties::effects`]: crate::objects::RoomObjectProperties::effects
+pub fn effective_damage(base: u32, effects: &[Effect]) -> u32 {
+    let invulnerable = effects.iter().any(|e| {
+        matches!(
+            e.effect,
+            EffectType::PowerEffect(PowerType::Fortify)
+                | EffectType::NaturalEffect(NaturalEffectType::Invulnerability)
+        )
+    });
+
+    if invulnerable {
+        0
+    } else {
+        base
+    }
+}
+
+/// Repair energy per tick needed to offset the decay of a structure of type
+/// `ty` with `hits_max` maximum hits.
+///
+/// Roads on swamps and walls have their decay multiplied along with their
+/// hits, so the multiplier is taken from `hits_max`.
+fn structure_upkeep(ty: StructureType, hits_max: u32, owned_room: bool) -> f64 {
+    let hits_per_tick = match ty {
+        StructureType::Road => {
+            let terrain_ratio = f64::from((hits_max / ROAD_HITS).max(1));
+            f64::from(ROAD_DECAY_AMOUNT) * terrain_ratio / f64::from(ROAD_DECAY_TIME)
+        }
+        StructureType::Container => {
+            let decay_time = if owned_room {
+                CONTAINER_DECAY_TIME_OWNED
+            } else {
+                CONTAINER_DECAY_TIME
+            };
+            f64::from(CONTAINER_DECAY) / f64::from(decay_time)
+        }
+        StructureType::Rampart => f64::from(RAMPART_DECAY_AMOUNT) / f64::from(RAMPART_DECAY_TIME),
+        _ => 0.0,
+    };
+    hits_per_tick * f64::from(REPAIR_COST)
+}
+
+fn can_spend_from(total: u32, amount: u32, reserve: u32) -> bool {
+    total.saturating_sub(reserve) >= amount
+}
+
+/// A single object found by [`Room::look_at`] and friends.
+///
+/// Each JS look result is decoded by its `type`, taking the object from the
+/// field of the same name. `terrain` results hold a string rather than an
+/// object, and are decoded into [`Terrain`].
 pub enum LookResult {
     Creep(Creep),
     Energy(Resource),
@@ -841,3 +1261,155 @@ impl TryFrom<Value> for PositionedLookResult {
         Ok(PositionedLookResult { x, y, look_result })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        can_spend_from, pack_path, structure_upkeep, unpack_path, AttackEvent, AttackType, Event,
+        EventType, Path, Step, ThreatSummary, UpgradeControllerEvent,
+    };
+    use crate::constants::{Direction, StructureType};
+
+    #[test]
+    fn threat_summary_totals() {
+        let mut summary = ThreatSummary::default();
+        summary.add_parts(2, 1, 0, 0, 3);
+        summary.add_parts(0, 0, 4, 5, 0);
+        assert_eq!(summary.creeps, 2);
+        assert_eq!(summary.tough, 3);
+        assert_eq!(summary.damage, 2 * 30 + 10);
+        assert_eq!(summary.heal_power, 4 * 12);
+        assert_eq!(summary.dismantle, 5 * 50);
+    }
+
+    #[test]
+    fn path_decodes_steps_or_string() {
+        let steps = r#"[{"x": 10, "y": 11, "dx": 1, "dy": -1, "direction": 2}]"#;
+        match serde_json::from_str::<Path>(steps).unwrap() {
+            Path::Vectorized(v) => {
+                assert_eq!(v.len(), 1);
+                assert_eq!((v[0].x, v[0].y, v[0].dx, v[0].dy), (10, 11, 1, -1));
+                assert_eq!(v[0].direction, Direction::TopRight);
+            }
+            other => panic!("expected vectorized path, got {:?}", other),
+        }
+
+        match serde_json::from_str::<Path>(r#""1011233""#).unwrap() {
+            Path::Serialized(s) => assert_eq!(s, "1011233"),
+            other => panic!("expected serialized path, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn event_log_keys_data_off_event_id() {
+        let log = r#"[
+            {"event": 1, "objectId": "a", "data": {"targetId": "b", "damage": 30, "attackType": 1}},
+            {"data": {"amount": 2, "energySpent": 2}, "objectId": "c", "event": 9}
+        ]"#;
+        let events: Vec<Event> = serde_json::from_str(log).unwrap();
+        assert_eq!(
+            events,
+            vec![
+                Event {
+                    event: EventType::Attack(AttackEvent {
+                        target_id: "b".to_owned(),
+                        damage: 30,
+                        attack_type: AttackType::Melee,
+                    }),
+                    object_id: "a".to_owned(),
+                },
+                Event {
+                    event: EventType::UpgradeController(UpgradeControllerEvent {
+                        amount: 2,
+                        energy_spent: 2,
+                    }),
+                    object_id: "c".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn spending_respects_reserve() {
+        assert!(can_spend_from(10_000, 5_000, 5_000));
+        assert!(!can_spend_from(10_000, 5_001, 5_000));
+        assert!(can_spend_from(1_000, 0, 5_000));
+        assert!(!can_spend_from(1_000, 1, 5_000));
+        assert!(!can_spend_from(0, 1, u32::MAX));
+    }
+
+    #[test]
+    fn packed_path_round_trip() {
+        let steps = unpack_path(&[10, 20, 0, 1, 4]).unwrap();
+        let positions: Vec<(u32, u32)> = steps.iter().map(|s| (s.x, s.y)).collect();
+        assert_eq!(positions, vec![(10, 19), (11, 18), (11, 19)]);
+        assert_eq!(steps[1].direction, Direction::TopRight);
+        assert_eq!((steps[2].dx, steps[2].dy), (0, 1));
+
+        assert_eq!(pack_path(&steps), Some(vec![10, 20, 0, 1, 4]));
+        assert_eq!(pack_path(&[]), Some(Vec::new()));
+        assert_eq!(unpack_path(&[]).map(|s| s.len()), Some(0));
+        assert!(unpack_path(&[10]).is_none());
+        assert!(unpack_path(&[10, 20, 8]).is_none());
+        assert!(unpack_path(&[10, 20, 255]).is_none());
+
+        let single = Step {
+            x: 0,
+            y: 5,
+            dx: -1,
+            dy: 0,
+            direction: Direction::Left,
+        };
+        assert_eq!(pack_path(&[single]), Some(vec![1, 5, 6]));
+    }
+
+    #[test]
+    fn packed_path_stays_in_room() {
+        // top left from the corner
+        assert!(unpack_path(&[0, 0, 7]).is_none());
+        // right from the east edge, after a valid step
+        assert!(unpack_path(&[48, 10, 2, 2]).is_none());
+        // start outside the room
+        assert!(unpack_path(&[50, 10]).is_none());
+        assert!(unpack_path(&[49, 49, 4]).is_none());
+
+        let from_outside = Step {
+            x: 0,
+            y: 5,
+            dx: 1,
+            dy: 0,
+            direction: Direction::Right,
+        };
+        assert_eq!(pack_path(&[from_outside]), None);
+    }
+
+    #[test]
+    fn decay_upkeep() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+        assert!(close(
+            structure_upkeep(StructureType::Road, 5_000, true),
+            0.001
+        ));
+        assert!(close(
+            structure_upkeep(StructureType::Road, 25_000, true),
+            0.005
+        ));
+        assert!(close(
+            structure_upkeep(StructureType::Road, 750_000, true),
+            0.15
+        ));
+        assert!(close(
+            structure_upkeep(StructureType::Container, 250_000, true),
+            0.1
+        ));
+        assert!(close(
+            structure_upkeep(StructureType::Container, 250_000, false),
+            0.5
+        ));
+        assert!(close(
+            structure_upkeep(StructureType::Rampart, 300_000, true),
+            0.03
+        ));
+        assert_eq!(structure_upkeep(StructureType::Spawn, 5_000, true), 0.0);
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{find, ResourceType, StructureType},
+    game,
+    local::{Position, RoomName},
+    objects::{HasPosition, Room, StructureProperties},
+};
+
+/// Plain-data summary of a room at one point in time, from
+/// [`Room::snapshot`].
+///
+/// Unlike [`Room`] this holds no references to JavaScript objects, so it can
+/// be kept across ticks and serialized to memory.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoomSnapshot {
+    pub name: RoomName,
+    /// Game tick the snapshot was taken on.
+    pub time: u32,
+    /// Level of the room's controller, or `None` if the room has none.
+    pub controller_level: Option<u32>,
+    pub energy_available: u32,
+    pub energy_capacity_available: u32,
+    /// Number of structures of each type, including other players'.
+    pub structure_counts: HashMap<StructureType, u32>,
+    pub sources: Vec<Position>,
+    /// Type of the room's mineral, if it has one.
+    pub mineral: Option<ResourceType>,
+}
+
+impl RoomSnapshot {
+    /// Number of structures of type `ty` in the snapshot.
+    pub fn structure_count(&self, ty: StructureType) -> u32 {
+        self.structure_counts.get(&ty).copied().unwrap_or(0)
+    }
+}
+
+impl Room {
+    /// Captures a [`RoomSnapshot`] of this room's current state.
+    pub fn snapshot(&self) -> RoomSnapshot {
+        let mut structure_counts = HashMap::new();
+        for structure in self.find(find::STRUCTURES) {
+            *structure_counts
+                .entry(structure.structure_type())
+                .or_insert(0) += 1;
+        }
+
+        RoomSnapshot {
+            name: self.name(),
+            time: game::time(),
+            controller_level: self.controller().map(|c| c.level()),
+            energy_available: self.energy_available(),
+            energy_capacity_available: self.energy_capacity_available(),
+            structure_counts,
+            sources: self
+                .find(find::SOURCES)
+                .into_iter()
+                .map(|s| s.pos())
+                .collect(),
+            mineral: self.find(find::MINERALS).first().map(|m| m.mineral_type()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::RoomSnapshot;
+    use crate::{
+        constants::{ResourceType, StructureType},
+        local::Position,
+    };
+
+    #[test]
+    fn snapshot_json_round_trip() {
+        let name = "W5N8".parse().unwrap();
+        let mut structure_counts = HashMap::new();
+        structure_counts.insert(StructureType::Extension, 20);
+        let snapshot = RoomSnapshot {
+            name,
+            time: 1234,
+            controller_level: Some(4),
+            energy_available: 300,
+            energy_capacity_available: 1300,
+            structure_counts,
+            sources: vec![Position::new(10, 12, name)],
+            mineral: Some(ResourceType::Keanium),
+        };
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let back: RoomSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, snapshot);
+        assert_eq!(back.structure_count(StructureType::Extension), 20);
+        assert_eq!(back.structure_count(StructureType::Tower), 0);
+    }
+}
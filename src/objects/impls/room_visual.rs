@@ -1,4 +1,4 @@
-use crate::local::RoomName;
+use crate::{local::RoomName, pathfinder::CostMatrix};
 use serde::Serialize;
 
 #[derive(Clone, Default, Serialize)]
@@ -411,6 +411,69 @@ impl RoomVisual {
         }
     }
 
+    /// Returns everything drawn to this visual so far this tick, as the
+    /// game's newline-delimited JSON format.
+    ///
+    /// The result can be stored (for example in `RawMemory`) and redrawn in a
+    /// later tick with [`RoomVisual::import`].
+    pub fn export(&self) -> String {
+        js_unwrap!(new RoomVisual(@{self.room_name} || undefined).export() || "")
+    }
+
+    /// Draws visuals previously returned by [`RoomVisual::export`].
+    pub fn import(&self, data: &str) {
+        js! { @(no_return) new RoomVisual(@{self.room_name} || undefined).import(@{data}); };
+    }
+
+    /// Removes everything drawn to this visual so far this tick.
+    pub fn clear(&self) {
+        js! { @(no_return) new RoomVisual(@{self.room_name} || undefined).clear(); };
+    }
+
+    /// Draws every non-zero cell of `matrix` as a colored square labelled with
+    /// its cost.
+    ///
+    /// Colors range from green for a cost of 1 to red for costs of `max` or
+    /// more.
+    pub fn draw_cost_matrix(&self, matrix: &CostMatrix<'_>, max: u8) {
+        let matrix = matrix.download();
+        let max = f32::from(max.max(1));
+        let mut visuals = Vec::new();
+
+        for x in 0..50u8 {
+            for y in 0..50u8 {
+                let cost = matrix.get(x, y);
+                if cost == 0 {
+                    continue;
+                }
+
+                let scale = (f32::from(cost) / max).min(1.0);
+                let color = format!(
+                    "#{:02x}{:02x}00",
+                    (255.0 * scale) as u8,
+                    (255.0 * (1.0 - scale)) as u8
+                );
+                let (x, y) = (f32::from(x), f32::from(y));
+
+                visuals.push(Visual::rect(
+                    x - 0.5,
+                    y - 0.5,
+                    1.0,
+                    1.0,
+                    Some(RectStyle::default().fill(&color).opacity(0.3)),
+                ));
+                visuals.push(Visual::text(
+                    x,
+                    y + 0.15,
+                    cost.to_string(),
+                    Some(TextStyle::default().color(&color).font(0.4)),
+                ));
+            }
+        }
+
+        self.draw_multi(&visuals);
+    }
+
     pub fn circle(&self, x: f32, y: f32, style: Option<CircleStyle>) {
         self.draw(&Visual::circle(x, y, style));
     }
@@ -1,4 +1,8 @@
-use crate::objects::Source;
+use crate::{
+    constants::{Terrain, HARVEST_POWER},
+    local::Position,
+    objects::{HasPosition, RoomTerrain, Source},
+};
 
 simple_accessors! {
     impl Source {
@@ -11,4 +15,70 @@ impl Source {
     pub fn ticks_to_regeneration(&self) -> u32 {
         js_unwrap!(Math.max(0, @{self.as_ref()}.ticksToRegeneration || 0))
     }
+
+    /// Energy a creep with `work_parts` active `WORK` parts would harvest from
+    /// this source this tick.
+    ///
+    /// See [`harvest_yield`].
+    pub fn harvest_yield(&self, work_parts: u32) -> u32 {
+        harvest_yield(work_parts, self.energy())
+    }
+
+    /// Number of tiles next to this source which aren't terrain walls, and so
+    /// can hold a harvesting creep.
+    ///
+    /// See [`open_positions`].
+    pub fn open_positions(&self) -> u8 {
+        let pos = self.pos();
+        open_positions(pos, &RoomTerrain::constructor(pos.room_name()))
+    }
+}
+
+/// Energy harvested in one tick by `work_parts` active `WORK` parts from a
+/// source holding `source_energy`.
+///
+/// A harvest can never take more than the source has left, so this is
+/// `work_parts * HARVEST_POWER` capped at `source_energy`.
+///
+/// ```
+/// use screeps::objects::harvest_yield;
+///
+/// assert_eq!(harvest_yield(5, 3000), 10);
+/// assert_eq!(harvest_yield(5, 4), 4);
+/// ```
+pub fn harvest_yield(work_parts: u32, source_energy: u32) -> u32 {
+    (work_parts * HARVEST_POWER).min(source_energy)
+}
+
+/// Counts the tiles among the up to 8 neighbors of `pos` which aren't
+/// terrain walls. Neighbors outside the room aren't counted.
+///
+/// Structures and creeps aren't considered.
+pub fn open_positions(pos: Position, terrain: &RoomTerrain) -> u8 {
+    count_open_neighbors(pos.x(), pos.y(), |x, y| terrain.get(x, y) == Terrain::Wall)
+}
+
+fn count_open_neighbors(x: u32, y: u32, is_wall: impl Fn(u32, u32) -> bool) -> u8 {
+    let mut open = 0;
+    for nx in x.saturating_sub(1)..=(x + 1).min(49) {
+        for ny in y.saturating_sub(1)..=(y + 1).min(49) {
+            if (nx, ny) != (x, y) && !is_wall(nx, ny) {
+                open += 1;
+            }
+        }
+    }
+    open
+}
+
+#[cfg(test)]
+mod test {
+    use super::count_open_neighbors;
+
+    #[test]
+    fn counts_open_neighbors() {
+        assert_eq!(count_open_neighbors(10, 10, |_, _| false), 8);
+        assert_eq!(count_open_neighbors(10, 10, |x, _| x == 9), 5);
+        assert_eq!(count_open_neighbors(0, 0, |_, _| false), 3);
+        assert_eq!(count_open_neighbors(49, 20, |_, y| y == 21), 3);
+    }
 }
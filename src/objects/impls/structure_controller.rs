@@ -1,6 +1,9 @@
 use stdweb::Value;
 
-use crate::{constants::ReturnCode, objects::StructureController};
+use crate::{
+    constants::{controller_downgrade, ReturnCode},
+    objects::{OwnedStructureProperties, StructureController},
+};
 
 simple_accessors! {
     impl StructureController {
@@ -16,25 +19,60 @@ simple_accessors! {
     }
 }
 
-#[derive(Debug)]
+/// A controller's reservation, from [`StructureController::reservation`].
+#[derive(Clone, Debug)]
 pub struct Reservation {
+    /// Name of the player holding the reservation.
     pub username: String,
     pub ticks_to_end: u32,
 }
 
-#[derive(Debug)]
+/// A controller's sign, from [`StructureController::sign`].
+#[derive(Clone, Debug)]
 pub struct Sign {
+    /// Name of the player who signed the controller.
     pub username: String,
     pub text: String,
+    /// Game tick the sign was set on.
     pub time: u32,
+    /// Real-world time the sign was set at.
     pub datetime: String, // todo: use real date type
 }
 
 impl StructureController {
+    /// Whether `upgradeController` is currently allowed on this controller.
+    ///
+    /// Upgrading is blocked for [`CONTROLLER_ATTACK_BLOCKED_UPGRADE`] ticks
+    /// after the controller is attacked; see
+    /// [`StructureController::upgrade_blocked`] for the ticks remaining.
+    ///
+    /// [`CONTROLLER_ATTACK_BLOCKED_UPGRADE`]: crate::constants::CONTROLLER_ATTACK_BLOCKED_UPGRADE
+    pub fn can_upgrade(&self) -> bool {
+        self.upgrade_blocked().unwrap_or(0) == 0
+    }
+
+    /// Whether this controller is yours and will downgrade in fewer than
+    /// `threshold_ticks` ticks.
+    ///
+    /// See [`controller_downgrade`] for the full timer at each level, and
+    /// [`StructureController::ticks_to_downgrade_max`] for this controller's.
+    pub fn is_downgrade_critical(&self, threshold_ticks: u32) -> bool {
+        self.my() && self.ticks_to_downgrade() < threshold_ticks
+    }
+
+    /// The full downgrade timer at this controller's level, or `None` for
+    /// unowned controllers.
+    pub fn ticks_to_downgrade_max(&self) -> Option<u32> {
+        controller_downgrade(self.level())
+    }
+
+    /// Activates safe mode, using one of [`StructureController::safe_mode_available`].
     pub fn activate_safe_mode(&self) -> ReturnCode {
         js_unwrap! {@{self.as_ref()}.activateSafeMode()}
     }
 
+    /// The current reservation of this controller, or `None` if it isn't
+    /// reserved.
     pub fn reservation(&self) -> Option<Reservation> {
         if let Value::Reference(r) = js!(return @{self.as_ref()}.reservation;) {
             Some(Reservation {
@@ -46,6 +84,7 @@ impl StructureController {
         }
     }
 
+    /// The sign on this controller, or `None` if it isn't signed.
     pub fn sign(&self) -> Option<Sign> {
         if let Value::Reference(r) = js!(return @{self.as_ref()}.sign;) {
             Some(Sign {
@@ -59,6 +98,7 @@ impl StructureController {
         }
     }
 
+    /// Gives up ownership of this controller, leaving the room neutral.
     pub fn unclaim(&self) -> ReturnCode {
         js_unwrap! {@{self.as_ref()}.unclaim()}
     }
@@ -10,6 +10,17 @@ simple_accessors! {
 }
 
 impl StructureFactory {
+    /// Whether this factory's level allows it to produce `ty`.
+    ///
+    /// Returns false for resources with no factory recipe. Doesn't check
+    /// components, cooldown or the `PWR_OPERATE_FACTORY` effect a leveled
+    /// factory needs to produce leveled commodities.
+    pub fn can_produce(&self, ty: ResourceType) -> bool {
+        ty.commodity_recipe()
+            .map(|recipe| recipe.allowed_at_level(self.level()))
+            .unwrap_or(false)
+    }
+
     pub fn produce(&self, ty: ResourceType) -> ReturnCode {
         js_unwrap! {@{self.as_ref()}.produce(__resource_type_num_to_str(@{ty as u32}))}
     }
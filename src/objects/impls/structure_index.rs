@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use crate::{
+    constants::{find, StructureType},
+    objects::{
+        Room, Structure, StructureExtension, StructureLab, StructureLink, StructureProperties,
+        StructureSpawn, StructureTower,
+    },
+};
+
+/// Structures owned by you in a room, bucketed by type, from
+/// [`Room::structure_index`].
+///
+/// Building the index runs a single `find`, so when a room's structures are
+/// queried by type many times in a tick, building it once and reusing it is
+/// cheaper than calling [`Room::find`] for each query.
+#[derive(Clone, Default)]
+pub struct StructureIndex {
+    by_type: HashMap<StructureType, Vec<Structure>>,
+}
+
+impl StructureIndex {
+    /// All indexed structures of type `ty`.
+    pub fn get(&self, ty: StructureType) -> &[Structure] {
+        self.by_type.get(&ty).map_or(&[], Vec::as_slice)
+    }
+
+    /// Number of indexed structures of type `ty`.
+    pub fn count(&self, ty: StructureType) -> usize {
+        self.get(ty).len()
+    }
+
+    /// Iterates over every indexed structure.
+    pub fn iter(&self) -> impl Iterator<Item = &Structure> {
+        self.by_type.values().flatten()
+    }
+
+    /// Your spawns in the room.
+    pub fn spawns(&self) -> Vec<StructureSpawn> {
+        self.collect(StructureType::Spawn, |s| match s {
+            Structure::Spawn(spawn) => Some(spawn),
+            _ => None,
+        })
+    }
+
+    /// Your extensions in the room.
+    pub fn extensions(&self) -> Vec<StructureExtension> {
+        self.collect(StructureType::Extension, |s| match s {
+            Structure::Extension(extension) => Some(extension),
+            _ => None,
+        })
+    }
+
+    /// Your towers in the room.
+    pub fn towers(&self) -> Vec<StructureTower> {
+        self.collect(StructureType::Tower, |s| match s {
+            Structure::Tower(tower) => Some(tower),
+            _ => None,
+        })
+    }
+
+    /// Your labs in the room.
+    pub fn labs(&self) -> Vec<StructureLab> {
+        self.collect(StructureType::Lab, |s| match s {
+            Structure::Lab(lab) => Some(lab),
+            _ => None,
+        })
+    }
+
+    /// Your links in the room.
+    pub fn links(&self) -> Vec<StructureLink> {
+        self.collect(StructureType::Link, |s| match s {
+            Structure::Link(link) => Some(link),
+            _ => None,
+        })
+    }
+
+    /// Clones out the indexed structures of type `ty`, unwrapped by `variant`.
+    fn collect<T: Clone>(
+        &self,
+        ty: StructureType,
+        variant: impl Fn(&Structure) -> Option<&T>,
+    ) -> Vec<T> {
+        self.get(ty).iter().filter_map(variant).cloned().collect()
+    }
+}
+
+impl Room {
+    /// Indexes all structures owned by you in this room by type, using a
+    /// single `find`.
+    pub fn structure_index(&self) -> StructureIndex {
+        let mut by_type: HashMap<StructureType, Vec<Structure>> = HashMap::new();
+        for owned in self.find(find::MY_STRUCTURES) {
+            by_type
+                .entry(owned.structure_type())
+                .or_default()
+                .push(owned.as_structure());
+        }
+        StructureIndex { by_type }
+    }
+}
@@ -1,12 +1,17 @@
+use std::collections::HashMap;
+
 use stdweb::Value;
 
 use crate::{
-    constants::{ResourceType, ReturnCode},
-    objects::{Creep, StructureLab},
+    constants::{ResourceType, ReturnCode, LAB_REACTION_AMOUNT},
+    game,
+    local::ObjectId,
+    objects::{Creep, HasCooldown, HasId, HasStore, StructureLab},
     traits::TryFrom,
 };
 
 impl StructureLab {
+    /// The mineral or compound this lab holds, if any.
     pub fn mineral_type(&self) -> Option<ResourceType> {
         let mineral_v = js! {
             const mineral = @{self.as_ref()}.mineralType;
@@ -22,6 +27,8 @@ impl StructureLab {
         }
     }
 
+    /// Boosts an adjacent creep's body parts with this lab's compound, boosting
+    /// every matching part, or at most `body_part_count` of them.
     pub fn boost_creep(&self, creep: &Creep, body_part_count: Option<u32>) -> ReturnCode {
         match body_part_count {
             None => js_unwrap! {@{self.as_ref()}.boostCreep(@{creep.as_ref()})},
@@ -29,15 +36,135 @@ impl StructureLab {
         }
     }
 
+    /// Produces a compound in this lab from the minerals in `lab1` and `lab2`,
+    /// which must be within 2 tiles of it.
     pub fn run_reaction(&self, lab1: &StructureLab, lab2: &StructureLab) -> ReturnCode {
         js_unwrap! {@{self.as_ref()}.runReaction(@{lab1.as_ref()}, @{lab2.as_ref()})}
     }
 
+    /// Breaks the compound in this lab back down into its components, placing
+    /// them in `lab1` and `lab2`.
     pub fn reverse_reaction(&self, lab1: &StructureLab, lab2: &StructureLab) -> ReturnCode {
         js_unwrap! {@{self.as_ref()}.reverseReaction(@{lab1.as_ref()}, @{lab2.as_ref()})}
     }
 
+    /// Removes all boosts from an adjacent creep, dropping half of the
+    /// compounds used on the ground.
     pub fn unboost_creep(&self, creep: &Creep) -> ReturnCode {
         js_unwrap!(@{self.as_ref()}.unboostCreep(@{creep.as_ref()}))
     }
 }
+
+/// Whether `output` can run a reaction from `in1` and `in2` right now.
+///
+/// Checks that neither input is empty and both hold at least
+/// [`LAB_REACTION_AMOUNT`] of minerals which react together, that `output`
+/// is either empty or already holds that reaction's product with room for
+/// another [`LAB_REACTION_AMOUNT`], and that `output` isn't on cooldown.
+/// Range between the labs isn't checked.
+pub fn can_run_reaction(output: &StructureLab, in1: &StructureLab, in2: &StructureLab) -> bool {
+    if output.cooldown() > 0 {
+        return false;
+    }
+    let (a, b) = match (in1.mineral_type(), in2.mineral_type()) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return false,
+    };
+    if in1.store_of(a) < LAB_REACTION_AMOUNT || in2.store_of(b) < LAB_REACTION_AMOUNT {
+        return false;
+    }
+    let product = match ResourceType::reaction_product(a, b) {
+        Some(product) => product,
+        None => return false,
+    };
+
+    match output.mineral_type() {
+        None => true,
+        Some(held) => {
+            held == product
+                && output.store_free_capacity(Some(product)) >= LAB_REACTION_AMOUNT as i32
+        }
+    }
+}
+
+/// Tracks when labs will come off cooldown, based on the reactions run in
+/// them, without reading each lab's `cooldown` every tick.
+///
+/// This lives entirely in Rust, so it's lost on global resets; labs which
+/// aren't tracked are assumed to be ready.
+#[derive(Clone, Debug, Default)]
+pub struct LabCooldownTracker {
+    ready_at: HashMap<ObjectId<StructureLab>, u32>,
+}
+
+impl LabCooldownTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs a reaction producing `compound` in `lab` and, if successful,
+    /// records its cooldown.
+    pub fn run_reaction(
+        &mut self,
+        lab: &StructureLab,
+        lab1: &StructureLab,
+        lab2: &StructureLab,
+        compound: ResourceType,
+    ) -> ReturnCode {
+        let result = lab.run_reaction(lab1, lab2);
+        if result == ReturnCode::Ok {
+            self.record_at(lab.id(), compound, game::time());
+        }
+        result
+    }
+
+    /// Records that a reaction producing `compound` ran in `lab` on tick
+    /// `time`, putting it on cooldown for the compound's
+    /// [`ResourceType::reaction_time`].
+    pub fn record_at(&mut self, lab: ObjectId<StructureLab>, compound: ResourceType, time: u32) {
+        let cooldown = compound.reaction_time().unwrap_or(0);
+        self.ready_at.insert(lab, time + cooldown);
+    }
+
+    /// The tick on which `lab` is expected to be able to react again, if it's
+    /// tracked.
+    pub fn ready_tick(&self, lab: ObjectId<StructureLab>) -> Option<u32> {
+        self.ready_at.get(&lab).copied()
+    }
+
+    /// Whether `lab` is expected to be off cooldown on tick `time`.
+    pub fn is_ready_at(&self, lab: ObjectId<StructureLab>, time: u32) -> bool {
+        self.ready_tick(lab).is_none_or(|ready| time >= ready)
+    }
+
+    /// Whether `lab` is expected to be off cooldown this tick.
+    pub fn is_ready(&self, lab: &StructureLab) -> bool {
+        self.is_ready_at(lab.id(), game::time())
+    }
+
+    /// Stops tracking `lab`, for instance after it's destroyed.
+    pub fn forget(&mut self, lab: ObjectId<StructureLab>) {
+        self.ready_at.remove(&lab);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LabCooldownTracker;
+    use crate::constants::ResourceType;
+
+    #[test]
+    fn tracks_reaction_cooldowns() {
+        let lab = "5f0a1b2c3d4e5f6a7b8c9d0e".parse().unwrap();
+        let mut tracker = LabCooldownTracker::new();
+        assert!(tracker.is_ready_at(lab, 100));
+
+        tracker.record_at(lab, ResourceType::UtriumHydride, 100);
+        assert_eq!(tracker.ready_tick(lab), Some(110));
+        assert!(!tracker.is_ready_at(lab, 109));
+        assert!(tracker.is_ready_at(lab, 110));
+
+        tracker.forget(lab);
+        assert_eq!(tracker.ready_tick(lab), None);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{constants::ReturnCode, objects::StructureLink};
+use crate::{
+    constants::{ReturnCode, LINK_CAPACITY, LINK_LOSS_RATIO},
+    objects::{HasCooldown, HasStore, StructureLink},
+};
 
 impl StructureLink {
     pub fn transfer_energy(&self, target: &StructureLink, amount: Option<u32>) -> ReturnCode {
@@ -10,3 +13,100 @@ impl StructureLink {
         }
     }
 }
+
+/// Plans transfers moving energy from `source_links` into `sink_links` for
+/// this tick.
+///
+/// Returns `(source_index, sink_index, amount)` triples, indexing into the
+/// given slices, each of which can be passed to
+/// [`StructureLink::transfer_energy`].
+///
+/// Each source link off cooldown sends once, to the sink with the most free
+/// space remaining. The game checks the sink's free space against the amount
+/// sent, before the [`LINK_LOSS_RATIO`] loss, so amounts never exceed the
+/// sink's free [`LINK_CAPACITY`], taking into account what earlier transfers
+/// planned into the same sink will deliver.
+pub fn balance_links(
+    source_links: &[StructureLink],
+    sink_links: &[StructureLink],
+) -> Vec<(usize, usize, u32)> {
+    let sources: Vec<(u32, u32)> = source_links
+        .iter()
+        .map(|link| (link.energy(), link.cooldown()))
+        .collect();
+    let sinks: Vec<u32> = sink_links.iter().map(|link| link.energy()).collect();
+
+    plan_link_transfers(&sources, &sinks)
+}
+
+/// Energy lost when a link sends `amount` energy.
+fn link_loss(amount: u32) -> u32 {
+    (amount as f64 * LINK_LOSS_RATIO as f64).ceil() as u32
+}
+
+/// Pure implementation of [`balance_links`], given `(energy, cooldown)` for
+/// each source and the current energy of each sink.
+fn plan_link_transfers(sources: &[(u32, u32)], sinks: &[u32]) -> Vec<(usize, usize, u32)> {
+    let mut free: Vec<u32> = sinks
+        .iter()
+        .map(|&energy| LINK_CAPACITY.saturating_sub(energy))
+        .collect();
+    let mut transfers = Vec::new();
+
+    for (source_idx, &(energy, cooldown)) in sources.iter().enumerate() {
+        if cooldown > 0 || energy == 0 {
+            continue;
+        }
+
+        let sink = free
+            .iter()
+            .enumerate()
+            .filter(|&(_, &space)| space > 0)
+            .max_by_key(|&(idx, &space)| (space, std::cmp::Reverse(idx)))
+            .map(|(idx, _)| idx);
+        let sink_idx = match sink {
+            Some(idx) => idx,
+            None => break,
+        };
+
+        // the game rejects amounts above the sink's free space, before loss
+        let amount = energy.min(free[sink_idx]);
+        free[sink_idx] -= amount - link_loss(amount);
+        transfers.push((source_idx, sink_idx, amount));
+    }
+
+    transfers
+}
+
+#[cfg(test)]
+mod test {
+    use super::{link_loss, plan_link_transfers};
+
+    #[test]
+    fn link_transfers_respect_capacity_and_loss() {
+        // source 1 is on cooldown; sink 0 has 100 free, sink 1 has 500 free.
+        let sources = [(800, 0), (800, 3), (800, 0)];
+        let sinks = [700, 300];
+        let plan = plan_link_transfers(&sources, &sinks);
+
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].0, 0);
+        assert_eq!(plan[0].1, 1);
+        assert_eq!(plan[1].0, 2);
+
+        let mut free = [100, 500];
+        for &(_, sink, amount) in &plan {
+            assert!(amount <= free[sink]);
+            free[sink] -= amount - link_loss(amount);
+        }
+        assert_eq!(plan[0].2, 500);
+        assert_eq!(plan[1], (2, 0, 100));
+    }
+
+    #[test]
+    fn link_loss_matches_game() {
+        assert_eq!(link_loss(100), 3);
+        assert_eq!(link_loss(800), 24);
+        assert_eq!(link_loss(1), 1);
+    }
+}
@@ -1,7 +1,101 @@
-use crate::{constants::ReturnCode, local::RoomName, objects::StructureObserver};
+use crate::{constants::ReturnCode, game, local::RoomName, objects::StructureObserver};
 
 impl StructureObserver {
     pub fn observe_room(&self, room_name: RoomName) -> ReturnCode {
         js_unwrap! {@{self.as_ref()}.observeRoom(@{room_name})}
     }
 }
+
+/// Round-robin schedule of rooms for a [`StructureObserver`] to cycle through,
+/// one room per tick.
+///
+/// As an [`Iterator`], this yields the rooms in order forever, wrapping around
+/// at the end of the list; it only ends if the list is empty.
+///
+/// The schedule lives on the Rust side and is not persisted between global
+/// resets.
+#[derive(Clone, Debug, Default)]
+pub struct ObserverSchedule {
+    rooms: Vec<RoomName>,
+    cursor: usize,
+}
+
+impl ObserverSchedule {
+    pub fn new(rooms: Vec<RoomName>) -> Self {
+        ObserverSchedule { rooms, cursor: 0 }
+    }
+
+    pub fn rooms(&self) -> &[RoomName] {
+        &self.rooms
+    }
+
+    /// Replaces the list of rooms to observe and restarts from its beginning.
+    pub fn set_rooms(&mut self, rooms: Vec<RoomName>) {
+        self.rooms = rooms;
+        self.cursor = 0;
+    }
+
+    /// Advances to the next room for which `skip` returns false.
+    ///
+    /// Returns `None` if the list is empty or every room is skipped.
+    pub fn next_unless(&mut self, mut skip: impl FnMut(RoomName) -> bool) -> Option<RoomName> {
+        for _ in 0..self.rooms.len() {
+            let room = self.rooms[self.cursor % self.rooms.len()];
+            self.cursor = (self.cursor + 1) % self.rooms.len();
+            if !skip(room) {
+                return Some(room);
+            }
+        }
+        None
+    }
+
+    /// Advances to the next room which is not currently visible, skipping
+    /// rooms with vision this tick.
+    pub fn next_without_vision(&mut self) -> Option<RoomName> {
+        self.next_unless(|room| game::rooms::get(room).is_some())
+    }
+
+    /// Observes the next room without vision this tick with `observer`.
+    ///
+    /// Returns the room chosen along with the result of
+    /// [`StructureObserver::observe_room`], or `None` if there was nothing to
+    /// observe.
+    pub fn observe_next(&mut self, observer: &StructureObserver) -> Option<(RoomName, ReturnCode)> {
+        let room = self.next_without_vision()?;
+        Some((room, observer.observe_room(room)))
+    }
+}
+
+impl Iterator for ObserverSchedule {
+    type Item = RoomName;
+
+    fn next(&mut self) -> Option<RoomName> {
+        self.next_unless(|_| false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ObserverSchedule;
+    use crate::local::RoomName;
+
+    fn names(names: &[&str]) -> Vec<RoomName> {
+        names.iter().map(|n| n.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn cycles_and_skips() {
+        let rooms = names(&["W1N1", "W2N1", "W3N1"]);
+        let mut schedule = ObserverSchedule::new(rooms.clone());
+        let seen: Vec<RoomName> = schedule.by_ref().take(4).collect();
+        assert_eq!(seen, vec![rooms[0], rooms[1], rooms[2], rooms[0]]);
+
+        assert_eq!(schedule.next_unless(|r| r == rooms[1]), Some(rooms[2]));
+        assert_eq!(schedule.next_unless(|_| true), None);
+
+        schedule.set_rooms(rooms[1..].to_vec());
+        assert_eq!(schedule.next(), Some(rooms[1]));
+
+        assert_eq!(ObserverSchedule::default().next(), None);
+    }
+}
@@ -1,7 +1,22 @@
-use crate::objects::StructurePowerBank;
+use crate::{constants::POWER_BANK_HIT_BACK, objects::StructurePowerBank};
 
 simple_accessors! {
     impl StructurePowerBank {
         pub fn power() -> u32 = power;
     }
 }
+
+/// Damage dealt back to a creep which attacks a [`StructurePowerBank`] for
+/// `damage_dealt` hits, per [`POWER_BANK_HIT_BACK`].
+///
+/// Ranged attacks are not hit back; this applies to melee `attack` only.
+///
+/// ```
+/// use screeps::objects::power_bank_hitback_damage;
+///
+/// assert_eq!(power_bank_hitback_damage(600), 300);
+/// assert_eq!(power_bank_hitback_damage(45), 22);
+/// ```
+pub fn power_bank_hitback_damage(damage_dealt: u32) -> u32 {
+    (damage_dealt as f32 * POWER_BANK_HIT_BACK).floor() as u32
+}
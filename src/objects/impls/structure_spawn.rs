@@ -1,9 +1,14 @@
+use std::{error::Error, fmt};
+
 use stdweb::Reference;
 
 use crate::{
-    constants::{Direction, Part, ReturnCode},
+    constants::{body_cost, Direction, Part, ReturnCode, MAX_CREEP_SIZE},
     memory::MemoryReference,
-    objects::{Creep, HasEnergyForSpawn, SizedRoomObject, Spawning, StructureSpawn},
+    objects::{
+        Creep, HasEnergyForSpawn, RoomObjectProperties, SizedRoomObject, Spawning,
+        StructureProperties, StructureSpawn,
+    },
     traits::TryInto,
 };
 
@@ -19,6 +24,10 @@ impl StructureSpawn {
         js_unwrap!(@{self.as_ref()}.memory)
     }
 
+    /// Spawns a creep with the given body and name.
+    ///
+    /// See [`StructureSpawn::preflight`] to check most failure cases locally,
+    /// or [`SpawnOptions::dry_run`] to have the game check them.
     pub fn spawn_creep(&self, body: &[Part], name: &str) -> ReturnCode {
         let ints = body.iter().map(|p| *p as u32).collect::<Vec<u32>>();
         (js! {
@@ -30,6 +39,9 @@ impl StructureSpawn {
         .expect("expected StructureSpawn::spawnCreep to return an integer return code")
     }
 
+    /// Spawns a creep with the given body and name, using `opts` to set its
+    /// memory, which structures to draw energy from, the directions it may
+    /// leave the spawn in, or to only check whether spawning would succeed.
     pub fn spawn_creep_with_options(
         &self,
         body: &[Part],
@@ -64,6 +76,58 @@ impl StructureSpawn {
         .expect("expected StructureSpawn::spawnCreep to return an integer return code")
     }
 
+    /// Checks locally whether [`StructureSpawn::spawn_creep`] would succeed
+    /// for this body and name, without issuing the intent.
+    ///
+    /// The body must be non-empty, at most [`MAX_CREEP_SIZE`] parts, and cost
+    /// no more than the room's energy capacity and currently available energy;
+    /// the name must not be taken by an existing creep; and the spawn must be
+    /// active and not already spawning.
+    pub fn preflight(&self, body: &[Part], name: &str) -> Result<(), SpawnError> {
+        let cost = check_body(body)?;
+
+        if js_unwrap!(@{name} in Game.creeps) {
+            return Err(SpawnError::NameExists);
+        }
+        if self.is_spawning() {
+            return Err(SpawnError::Busy);
+        }
+        if !self.is_active() {
+            return Err(SpawnError::RclNotEnough);
+        }
+
+        let room = self
+            .room()
+            .expect("expected StructureSpawn to be in a visible room");
+        let capacity = room.energy_capacity_available();
+        if cost > capacity {
+            return Err(SpawnError::TooExpensive { cost, capacity });
+        }
+        let available = room.energy_available();
+        if cost > available {
+            return Err(SpawnError::NotEnoughEnergy { cost, available });
+        }
+
+        Ok(())
+    }
+
+    /// Builds the largest body made of whole repetitions of `pattern` which
+    /// this spawn's room could afford at full energy.
+    ///
+    /// The pattern is repeated as many times as fits within the room's
+    /// `energy_capacity_available`, `max_parts`, and [`MAX_CREEP_SIZE`].
+    /// Returns an empty body if not even one repetition fits.
+    pub fn max_affordable_body(&self, pattern: &[Part], max_parts: usize) -> Vec<Part> {
+        let energy = self
+            .room()
+            .expect("expected StructureSpawn to be in a visible room")
+            .energy_capacity_available();
+
+        BodyScaler::new(pattern.to_vec())
+            .max_parts(max_parts)
+            .body(energy)
+    }
+
     // TODO: support actually using Spawning properties.
     pub fn is_spawning(&self) -> bool {
         js_unwrap!(Boolean(@{self.as_ref()}.spawning))
@@ -78,6 +142,213 @@ impl StructureSpawn {
     }
 }
 
+/// Validates a body's size, returning its cost.
+fn check_body(body: &[Part]) -> Result<u32, SpawnError> {
+    if body.is_empty() || body.len() > MAX_CREEP_SIZE as usize {
+        return Err(SpawnError::InvalidBody(body.len()));
+    }
+    Ok(body_cost(body))
+}
+
+/// Reason a [`StructureSpawn::spawn_creep`] would fail, as reported by
+/// [`StructureSpawn::preflight`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnError {
+    /// The body has this many parts, which is either none or more than
+    /// [`MAX_CREEP_SIZE`].
+    InvalidBody(usize),
+    /// A creep with this name already exists.
+    NameExists,
+    /// The spawn is already spawning a creep.
+    Busy,
+    /// The spawn isn't usable at the room's controller level.
+    RclNotEnough,
+    /// The body costs more than the room can ever hold.
+    TooExpensive { cost: u32, capacity: u32 },
+    /// The room can afford the body, but doesn't currently hold enough energy.
+    NotEnoughEnergy { cost: u32, available: u32 },
+}
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpawnError::InvalidBody(len) => write!(
+                f,
+                "body has {} parts, expected 1 to {}",
+                len, MAX_CREEP_SIZE
+            ),
+            SpawnError::NameExists => write!(f, "creep name already taken"),
+            SpawnError::Busy => write!(f, "spawn is busy"),
+            SpawnError::RclNotEnough => write!(f, "spawn is inactive at this controller level"),
+            SpawnError::TooExpensive { cost, capacity } => write!(
+                f,
+                "body costs {}, more than room capacity of {}",
+                cost, capacity
+            ),
+            SpawnError::NotEnoughEnergy { cost, available } => write!(
+                f,
+                "body costs {}, room has {} energy available",
+                cost, available
+            ),
+        }
+    }
+}
+
+impl Error for SpawnError {}
+
+/// How [`BodyScaler`] adds `MOVE` parts to a body.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MoveSpeed {
+    /// Use the pattern as-is, including any `MOVE` parts in it.
+    Manual,
+    /// One `MOVE` per other part, for full speed on plains.
+    Plains,
+    /// One `MOVE` per two other parts, for full speed on roads.
+    Roads,
+}
+
+/// Scales a repeated body pattern to the energy available for spawning, for
+/// one role's bodies.
+///
+/// The pattern is repeated as many times as fits within the energy, the
+/// part cap and [`MAX_CREEP_SIZE`]. With [`MoveSpeed::Plains`] or
+/// [`MoveSpeed::Roads`], any `MOVE` parts in the pattern are dropped and the
+/// `MOVE` parts needed for full speed are appended after the repeated pattern
+/// instead, counting toward the energy and size limits. Bodies are assumed to
+/// be fully loaded: empty `CARRY` parts generate no fatigue, but are still
+/// counted.
+///
+/// # Example
+///
+/// ```
+/// use screeps::{objects::BodyScaler, Part};
+///
+/// let body = BodyScaler::hauler().body(550);
+/// assert_eq!(body.iter().filter(|&&p| p == Part::Carry).count(), 7);
+/// assert_eq!(body.iter().filter(|&&p| p == Part::Move).count(), 4);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BodyScaler {
+    pattern: Vec<Part>,
+    max_parts: usize,
+    max_repeats: usize,
+    move_speed: MoveSpeed,
+}
+
+impl BodyScaler {
+    /// Creates a scaler repeating `pattern` as-is, up to [`MAX_CREEP_SIZE`]
+    /// parts.
+    pub fn new(pattern: Vec<Part>) -> Self {
+        BodyScaler {
+            pattern,
+            max_parts: MAX_CREEP_SIZE as usize,
+            max_repeats: usize::MAX,
+            move_speed: MoveSpeed::Manual,
+        }
+    }
+
+    /// `WORK` parts moving at full speed on roads, capped at 5 `WORK` parts,
+    /// which fully drain an owned source.
+    pub fn harvester() -> Self {
+        BodyScaler::new(vec![Part::Work])
+            .max_repeats(5)
+            .move_speed(MoveSpeed::Roads)
+    }
+
+    /// `CARRY` parts moving at full speed on roads.
+    pub fn hauler() -> Self {
+        BodyScaler::new(vec![Part::Carry]).move_speed(MoveSpeed::Roads)
+    }
+
+    /// Paired `WORK` and `CARRY` parts moving at full speed on plains, for
+    /// building and upgrading.
+    pub fn worker() -> Self {
+        BodyScaler::new(vec![Part::Work, Part::Carry]).move_speed(MoveSpeed::Plains)
+    }
+
+    /// `ATTACK` parts moving at full speed on plains.
+    pub fn defender() -> Self {
+        BodyScaler::new(vec![Part::Attack]).move_speed(MoveSpeed::Plains)
+    }
+
+    /// Sets the maximum total number of parts, including added `MOVE` parts.
+    pub fn max_parts(mut self, max_parts: usize) -> Self {
+        self.max_parts = max_parts;
+        self
+    }
+
+    /// Sets the maximum number of times the pattern is repeated.
+    pub fn max_repeats(mut self, max_repeats: usize) -> Self {
+        self.max_repeats = max_repeats;
+        self
+    }
+
+    pub fn move_speed(mut self, move_speed: MoveSpeed) -> Self {
+        self.move_speed = move_speed;
+        self
+    }
+
+    /// Builds the largest body affordable with `energy`, usually a room's
+    /// `energy_capacity_available`.
+    ///
+    /// Returns an empty body if not even one repetition fits.
+    pub fn body(&self, energy: u32) -> Vec<Part> {
+        let unit: Vec<Part> = match self.move_speed {
+            MoveSpeed::Manual => self.pattern.clone(),
+            MoveSpeed::Plains | MoveSpeed::Roads => self
+                .pattern
+                .iter()
+                .copied()
+                .filter(|&p| p != Part::Move)
+                .collect(),
+        };
+        if unit.is_empty() {
+            return Vec::new();
+        }
+        let unit_cost = body_cost(&unit);
+        let max_parts = self.max_parts.min(MAX_CREEP_SIZE as usize);
+
+        let mut repeats = 0;
+        while repeats < self.max_repeats {
+            let parts = (repeats + 1) * unit.len();
+            let moves = self.moves_for(parts);
+            let cost = (repeats + 1) as u32 * unit_cost + moves as u32 * Part::Move.cost();
+            if parts + moves > max_parts || cost > energy {
+                break;
+            }
+            repeats += 1;
+        }
+
+        let parts = repeats * unit.len();
+        let mut body: Vec<Part> = unit.iter().copied().cycle().take(parts).collect();
+        body.extend(std::iter::repeat_n(Part::Move, self.moves_for(parts)));
+        body
+    }
+
+    fn moves_for(&self, parts: usize) -> usize {
+        match self.move_speed {
+            MoveSpeed::Manual => 0,
+            MoveSpeed::Plains => parts,
+            MoveSpeed::Roads => parts.div_ceil(2),
+        }
+    }
+}
+
+/// Options for [`StructureSpawn::spawn_creep_with_options`].
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::{game, Direction, Part, ReturnCode, SpawnOptions};
+///
+/// let spawn = game::spawns::values().pop().unwrap();
+/// let body = [Part::Work, Part::Carry, Part::Move];
+/// let opts = SpawnOptions::new().dry_run(true);
+/// if spawn.spawn_creep_with_options(&body, "worker", &opts) == ReturnCode::Ok {
+///     let opts = SpawnOptions::new().directions(&[Direction::Top, Direction::Right]);
+///     spawn.spawn_creep_with_options(&body, "worker", &opts);
+/// }
+/// ```
 #[derive(Default)]
 pub struct SpawnOptions {
     memory: Option<MemoryReference>,
@@ -106,11 +377,14 @@ impl SpawnOptions {
         self
     }
 
+    /// If set, only checks whether spawning would succeed, without spawning.
     pub fn dry_run(mut self, dry_run: bool) -> Self {
         self.dry_run = dry_run;
         self
     }
 
+    /// Directions the new creep may leave the spawn in, in order of
+    /// preference.
     pub fn directions(mut self, directions: &[Direction]) -> Self {
         self.directions = directions.iter().map(|d| *d as u32).collect();
         self
@@ -119,7 +393,6 @@ impl SpawnOptions {
 
 simple_accessors! {
     impl Spawning {
-        pub fn directions() -> Vec<Direction> = directions;
         pub fn name() -> String = name;
         pub fn need_time() -> u32 = needTime;
         pub fn remaining_time() -> u32 = remainingTime;
@@ -128,12 +401,40 @@ simple_accessors! {
 }
 
 impl Spawning {
+    /// Directions the creep may leave the spawn in, in order of preference.
+    ///
+    /// Empty if no directions were given, in which case any free direction is
+    /// used.
+    pub fn directions(&self) -> Vec<Direction> {
+        // undefined unless directions were given when spawning or set since
+        js_unwrap!(@{self.as_ref()}.directions || [])
+    }
+
+    /// Cancels spawning this creep, refunding none of its energy.
     pub fn cancel(&self) -> ReturnCode {
         js_unwrap!(@{self.as_ref()}.cancel())
     }
 
+    /// Changes the directions the creep may leave the spawn in.
     pub fn set_directions(&self, directions: &[Direction]) -> ReturnCode {
         let int_dirs: Vec<u32> = directions.iter().map(|d| *d as u32).collect();
         js_unwrap!(@{self.as_ref()}.setDirections(@{int_dirs}))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{check_body, SpawnError};
+    use crate::constants::{Part, MAX_CREEP_SIZE};
+
+    #[test]
+    fn body_checks() {
+        assert_eq!(check_body(&[]), Err(SpawnError::InvalidBody(0)));
+        let too_big = vec![Part::Move; MAX_CREEP_SIZE as usize + 1];
+        assert_eq!(
+            check_body(&too_big),
+            Err(SpawnError::InvalidBody(MAX_CREEP_SIZE as usize + 1))
+        );
+        assert_eq!(check_body(&[Part::Work, Part::Carry, Part::Move]), Ok(200));
+    }
+}
@@ -1,10 +1,17 @@
+use std::{error::Error, fmt};
+
 use crate::{
-    constants::{ResourceType, ReturnCode},
+    constants::{terminal_send_cost, ResourceType, ReturnCode},
+    game::map,
     local::RoomName,
-    objects::StructureTerminal,
+    objects::{HasCooldown, HasPosition, HasStore, StructureTerminal},
 };
 
 impl StructureTerminal {
+    /// Sends `amount` of a resource to the terminal in `destination`, paying
+    /// [`StructureTerminal::send_cost`] in energy from this terminal.
+    ///
+    /// `description` is shown to both players in the transaction log.
     pub fn send(
         &self,
         resource_type: ResourceType,
@@ -19,4 +26,88 @@ impl StructureTerminal {
                                   @{description} || undefined)
         }
     }
+
+    /// Energy this terminal would spend to send `amount` of a resource to
+    /// `destination`.
+    ///
+    /// See [`terminal_send_cost`].
+    pub fn send_cost(&self, amount: u32, destination: RoomName) -> u32 {
+        let range = map::get_room_linear_distance(self.pos().room_name(), destination, true);
+        terminal_send_cost(amount, range)
+    }
+
+    /// Checks whether [`StructureTerminal::send`] could currently succeed,
+    /// without issuing an intent.
+    ///
+    /// This checks, in order, that the terminal is off cooldown (see
+    /// [`TERMINAL_COOLDOWN`]), that the terminal holds `amount` of the
+    /// resource, and that it holds enough energy to pay
+    /// [`StructureTerminal::send_cost`] on top of it.
+    ///
+    /// [`TERMINAL_COOLDOWN`]: crate::constants::TERMINAL_COOLDOWN
+    pub fn can_send(
+        &self,
+        resource_type: ResourceType,
+        amount: u32,
+        destination: RoomName,
+    ) -> Result<(), SendError> {
+        let cooldown = self.cooldown();
+        if cooldown > 0 {
+            return Err(SendError::Cooldown(cooldown));
+        }
+
+        let available = self.store_of(resource_type);
+        if available < amount {
+            return Err(SendError::NotEnoughResource {
+                needed: amount,
+                available,
+            });
+        }
+
+        let cost = self.send_cost(amount, destination);
+        let energy_needed = match resource_type {
+            ResourceType::Energy => amount + cost,
+            _ => cost,
+        };
+        let energy = self.energy();
+        if energy < energy_needed {
+            return Err(SendError::NotEnoughEnergy {
+                needed: energy_needed,
+                available: energy,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Reason a [`StructureTerminal::send`] would fail, as reported by
+/// [`StructureTerminal::can_send`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendError {
+    /// The terminal is still on cooldown for this many ticks.
+    Cooldown(u32),
+    /// The terminal doesn't hold enough of the resource being sent.
+    NotEnoughResource { needed: u32, available: u32 },
+    /// The terminal doesn't hold enough energy to pay for the transfer,
+    /// including the amount itself when sending energy.
+    NotEnoughEnergy { needed: u32, available: u32 },
 }
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SendError::Cooldown(ticks) => write!(f, "terminal on cooldown for {} ticks", ticks),
+            SendError::NotEnoughResource { needed, available } => write!(
+                f,
+                "terminal holds {} of resource, needed {}",
+                available, needed
+            ),
+            SendError::NotEnoughEnergy { needed, available } => {
+                write!(f, "terminal holds {} energy, needed {}", available, needed)
+            }
+        }
+    }
+}
+
+impl Error for SendError {}
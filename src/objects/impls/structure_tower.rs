@@ -4,6 +4,10 @@ use crate::{
 };
 
 impl StructureTower {
+    /// Attacks a creep, power creep or structure anywhere in the room, dealing
+    /// less damage the further away it is; see [`tower_effectiveness`].
+    ///
+    /// [`tower_effectiveness`]: crate::constants::tower_effectiveness
     pub fn attack<T>(&self, target: &T) -> ReturnCode
     where
         T: Attackable,
@@ -11,6 +15,8 @@ impl StructureTower {
         js_unwrap! { @{self.as_ref()}.attack( @{target.as_ref()} ) }
     }
 
+    /// Heals a creep or power creep anywhere in the room, healing less the
+    /// further away it is.
     pub fn heal<T>(&self, target: &T) -> ReturnCode
     where
         T: SharedCreepProperties,
@@ -18,6 +24,8 @@ impl StructureTower {
         js_unwrap! { @{self.as_ref()}.heal( @{target.as_ref()} ) }
     }
 
+    /// Repairs a structure anywhere in the room, repairing less the further
+    /// away it is.
     pub fn repair<T>(&self, target: &T) -> ReturnCode
     where
         T: StructureProperties,
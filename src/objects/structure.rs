@@ -3,7 +3,7 @@ use stdweb::{InstanceOf, Reference, ReferenceType, Value};
 use super::*;
 use crate::{
     constants::StructureType,
-    objects::{Attackable, CanDecay, HasCooldown, HasEnergyForSpawn, HasStore},
+    objects::{Attackable, CanDecay, HasCooldown, HasEnergyForSpawn, HasStore, ResourceSink},
     traits::FromExpectedType,
     ConversionError,
 };
@@ -221,6 +221,17 @@ impl Structure {
             v => v
         )
     }
+
+    /// Cast this as a destination for hauled resources.
+    pub fn as_resource_sink(&self) -> Option<&dyn ResourceSink> {
+        match_some_structure_variants!(
+            self,
+            {
+                Container, Extension, Lab, Nuker, PowerSpawn, Spawn, Storage, Terminal, Tower
+            },
+            v => v
+        )
+    }
 }
 
 impl AsRef<Reference> for Structure {
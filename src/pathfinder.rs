@@ -159,6 +159,17 @@ impl Default for CostMatrix<'static> {
     }
 }
 
+impl<'a> CostMatrix<'a> {
+    /// Copies all data out of this JavaScript CostMatrix.
+    pub fn download(&self) -> LocalCostMatrix {
+        let bits: TypedArray<u8> = js_unwrap!(@{&self.inner}._bits);
+
+        LocalCostMatrix {
+            bits: bits.to_vec(),
+        }
+    }
+}
+
 impl<'a> Into<MultiRoomCostResult<'a>> for CostMatrix<'a> {
     fn into(self) -> MultiRoomCostResult<'a> {
         MultiRoomCostResult::CostMatrix(self)
@@ -321,7 +332,7 @@ where
     flee: bool,
     max_ops: u32,
     max_rooms: u32,
-    max_cost: f64,
+    max_cost: Option<u32>,
     heuristic_weight: f64,
 }
 
@@ -340,7 +351,7 @@ impl Default for SearchOptions<'static, fn(RoomName) -> MultiRoomCostResult<'sta
             flee: false,
             max_ops: 2000,
             max_rooms: 16,
-            max_cost: f64::INFINITY,
+            max_cost: None,
             heuristic_weight: 1.2,
         }
     }
@@ -420,10 +431,13 @@ where
         self
     }
 
-    /// Sets maximum path cost - default `f64::Infinity`.
+    /// Sets maximum path cost - default unlimited.
+    ///
+    /// If the cheapest path found would cost more than this, the search is
+    /// aborted and [`SearchResults::incomplete`] is set.
     #[inline]
-    pub fn max_cost(mut self, cost: f64) -> Self {
-        self.max_cost = cost;
+    pub fn max_cost(mut self, cost: u32) -> Self {
+        self.max_cost = Some(cost);
         self
     }
 
@@ -437,8 +451,14 @@ where
 
 pub struct SearchResults {
     path: Array,
+    /// Number of pathfinding operations performed.
     pub ops: u32,
+    /// Total cost of the returned path.
     pub cost: u32,
+    /// Whether the search failed to reach a goal, either because no path
+    /// exists or because it was cut off by [`SearchOptions::max_ops`] or
+    /// [`SearchOptions::max_cost`]. The path returned is then a partial path
+    /// toward the closest point found.
     pub incomplete: bool,
 }
 
@@ -502,6 +522,38 @@ where
     search_real(origin.pos(), &goals_js, opts)
 }
 
+/// Finds the item in `items` with the shortest path from `origin`.
+///
+/// This runs a single [`search_many`] toward every item, with each item
+/// considered reached when within `range` of it, and returns the item nearest
+/// to the end of the resulting path. Returns `None` if `items` is empty or if
+/// no complete path could be found.
+///
+/// To compare by linear range instead, see [`closest`][crate::objects::closest].
+pub fn closest_by_path<'a, 'b, O, T, F>(
+    origin: &O,
+    items: &'b [T],
+    range: u32,
+    opts: SearchOptions<'a, F>,
+) -> Option<&'b T>
+where
+    O: ?Sized + HasPosition,
+    T: HasPosition,
+    F: FnMut(RoomName) -> MultiRoomCostResult<'a> + 'a,
+{
+    let origin = origin.pos();
+    let results = search_many(&origin, items.iter().map(|item| (item.pos(), range)), opts);
+    if results.incomplete {
+        return None;
+    }
+    let end = results.load_local_path().last().copied().unwrap_or(origin);
+
+    items
+        .iter()
+        .filter(|item| end.get_range_to(*item) <= range)
+        .min_by_key(|item| end.get_range_to(*item))
+}
+
 fn search_real<'a, F>(
     origin: Position,
     goal: &Reference,
@@ -521,6 +573,8 @@ where
         ..
     } = opts;
 
+    let max_cost = max_cost.map(f64::from).unwrap_or(f64::INFINITY);
+
     let mut raw_callback = opts.room_callback;
 
     let mut callback_boxed = move |room_name: RoomName| -> Value { raw_callback(room_name).into() };
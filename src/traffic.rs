@@ -0,0 +1,222 @@
+//! Coordinating creep movement within a tick.
+//!
+//! Issuing `move` intents creep-by-creep makes creeps stall behind each other:
+//! a creep can't step onto a tile whose occupant isn't registered as leaving,
+//! and idle creeps block whole corridors. [`TrafficManager`] collects every
+//! creep's intended move first, then resolves them together, so that chains
+//! of creeps move in step, creeps facing each other swap places, and idle
+//! creeps are pushed out of the way by swapping with the creep that wants
+//! their tile.
+use std::collections::HashMap;
+
+use crate::{
+    constants::{Direction, ReturnCode},
+    local::Position,
+    objects::{Creep, HasPosition},
+};
+
+/// Collects intended creep moves for a tick and issues them together.
+///
+/// # Example
+///
+/// ```no_run
+/// use screeps::{game, prelude::*, traffic::TrafficManager, Direction};
+///
+/// let mut traffic = TrafficManager::new();
+/// for creep in game::creeps::values() {
+///     if creep.name().starts_with("hauler") {
+///         traffic.register_move(&creep, Direction::Top);
+///     } else {
+///         traffic.register_stationary(&creep);
+///     }
+/// }
+/// traffic.resolve();
+/// ```
+#[derive(Default)]
+pub struct TrafficManager {
+    creeps: Vec<Creep>,
+    current: Vec<Position>,
+    desired: Vec<Option<Position>>,
+}
+
+impl TrafficManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers that `creep` wants to move one tile in direction `dir` this
+    /// tick.
+    ///
+    /// Moves requested earlier take priority when two creeps want the same
+    /// tile.
+    pub fn register_move(&mut self, creep: &Creep, dir: Direction) {
+        let pos = creep.pos();
+        self.creeps.push(creep.clone());
+        self.current.push(pos);
+        let (dx, dy) = dir.offset();
+        self.desired
+            .push(Some(pos + (i32::from(dx), i32::from(dy))));
+    }
+
+    /// Registers that `creep` has nowhere to go this tick, so it may be
+    /// swapped backwards by a creep which wants its tile.
+    ///
+    /// Creeps which aren't registered at all are treated as immovable.
+    pub fn register_stationary(&mut self, creep: &Creep) {
+        self.creeps.push(creep.clone());
+        self.current.push(creep.pos());
+        self.desired.push(None);
+    }
+
+    /// Resolves all registered moves and issues a `move` intent for each
+    /// creep which should move, returning each creep moved along with the
+    /// result of [`Creep::try_move`].
+    pub fn resolve(&self) -> Vec<(&Creep, ReturnCode)> {
+        resolve_moves(&self.current, &self.desired)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, dest)| {
+                let dir = self.current[i].get_direction_to(&dest?)?;
+                Some((&self.creeps[i], self.creeps[i].try_move(dir)))
+            })
+            .collect()
+    }
+}
+
+/// Resolves creeps at `current` which want to move to `desired`, returning
+/// where each creep ends up moving to, or `None` for creeps which stay.
+fn resolve_moves(current: &[Position], desired: &[Option<Position>]) -> Vec<Option<Position>> {
+    let occupant: HashMap<Position, usize> = current
+        .iter()
+        .enumerate()
+        .map(|(i, &pos)| (pos, i))
+        .collect();
+    let mut claimed: HashMap<Position, usize> = HashMap::new();
+    let mut result: Vec<Option<Position>> = vec![None; current.len()];
+    let mut resolved = vec![false; current.len()];
+
+    for (i, target) in desired.iter().enumerate() {
+        let target = match target {
+            Some(target) if !resolved[i] && *target != current[i] => *target,
+            _ => continue,
+        };
+        resolved[i] = true;
+        if claimed.contains_key(&target) {
+            continue;
+        }
+
+        match occupant.get(&target) {
+            Some(&j)
+                if !resolved[j]
+                    && desired[j] == Some(current[i])
+                    && !claimed.contains_key(&current[i]) =>
+            {
+                // head-on: both creeps swap places, unless an earlier creep
+                // already claimed our tile
+                claimed.insert(target, i);
+                claimed.insert(current[i], j);
+                result[i] = Some(target);
+                result[j] = Some(current[i]);
+                resolved[j] = true;
+            }
+            Some(&j) if desired[j].is_none() && result[j].is_none() => {
+                // idle creep in the way: push it back onto our tile
+                if claimed.contains_key(&current[i]) {
+                    continue;
+                }
+                claimed.insert(target, i);
+                claimed.insert(current[i], j);
+                result[i] = Some(target);
+                result[j] = Some(current[i]);
+            }
+            _ => {
+                // empty tile, or an occupant which is moving itself; checked
+                // below once everyone's moves are known
+                claimed.insert(target, i);
+                result[i] = Some(target);
+            }
+        }
+    }
+
+    // creeps following a creep which ended up staying put can't move either,
+    // which may in turn block the creeps following them
+    loop {
+        let mut changed = false;
+        for i in 0..current.len() {
+            if let Some(target) = result[i] {
+                if let Some(&j) = occupant.get(&target) {
+                    if j != i && result[j].is_none() {
+                        result[i] = None;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::resolve_moves;
+    use crate::local::Position;
+
+    fn pos(x: u32, y: u32) -> Position {
+        Position::new(x, y, "E1N1".parse().unwrap())
+    }
+
+    #[test]
+    fn chain_moves_together() {
+        let current = [pos(10, 10), pos(11, 10), pos(12, 10)];
+        let desired = [Some(pos(11, 10)), Some(pos(12, 10)), Some(pos(13, 10))];
+        assert_eq!(
+            resolve_moves(&current, &desired),
+            vec![Some(pos(11, 10)), Some(pos(12, 10)), Some(pos(13, 10))]
+        );
+    }
+
+    #[test]
+    fn head_on_creeps_swap() {
+        let current = [pos(10, 10), pos(11, 10)];
+        let desired = [Some(pos(11, 10)), Some(pos(10, 10))];
+        assert_eq!(
+            resolve_moves(&current, &desired),
+            vec![Some(pos(11, 10)), Some(pos(10, 10))]
+        );
+    }
+
+    #[test]
+    fn idle_creep_is_pushed_back() {
+        let current = [pos(10, 10), pos(11, 10)];
+        let desired = [Some(pos(11, 10)), None];
+        assert_eq!(
+            resolve_moves(&current, &desired),
+            vec![Some(pos(11, 10)), Some(pos(10, 10))]
+        );
+    }
+
+    #[test]
+    fn blocked_chain_stays() {
+        // the front creep loses its tile to an earlier request, so the
+        // creep following it has to wait as well
+        let current = [pos(20, 20), pos(10, 10), pos(11, 10)];
+        let desired = [Some(pos(21, 20)), Some(pos(11, 10)), Some(pos(21, 20))];
+        assert_eq!(
+            resolve_moves(&current, &desired),
+            vec![Some(pos(21, 20)), None, None]
+        );
+    }
+
+    #[test]
+    fn head_on_swap_respects_earlier_claim() {
+        // the first creep claims the middle tile before the other two try to
+        // swap through it, so nobody can move
+        let current = [pos(9, 10), pos(10, 10), pos(11, 10)];
+        let desired = [Some(pos(10, 10)), Some(pos(11, 10)), Some(pos(10, 10))];
+        assert_eq!(resolve_moves(&current, &desired), vec![None, None, None]);
+    }
+}